@@ -1,29 +1,30 @@
 mod source;
-use source::Logger;
+use source::{JsonLinesCodec, LengthDelimitedMsgPackCodec, Logger};
 
 use std::{
     fs::File,
     io::{self, BufWriter},
+    net::SocketAddr,
     path::PathBuf,
+    time::Duration,
 };
 
 use anyhow::{anyhow, Context, Result};
 use argh::FromArgs;
 use futures::{stream::SelectAll, StreamExt};
-use tokio::{
-    signal::unix::{signal, SignalKind},
-    sync::broadcast,
-};
+use tokio::signal::unix::{signal, SignalKind};
 use tokio_stream::wrappers::SignalStream;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, trace, warn};
 use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
 
 use faasrail_loadgen::{
+    metrics::MetricsRegistry,
     sink::{
         backend::{NoOp as NoOpSink, NoResponse},
         SinkClient,
     },
-    source::{MinuteRange, Poisson, SourceClient},
+    source::{MinuteRange, Poisson, RetryPolicy, SourceClient},
 };
 
 const DEFAULT_MINIO_HOSTPORT: &str = "localhost:59000";
@@ -62,9 +63,59 @@ struct Cli {
     /// name of the MinIO bucket
     #[argh(option, default = "String::from(DEFAULT_MINIO_BUCKET_NAME)")]
     minio_bucket: String,
+
+    /// enable closed-loop pacing correction with the given gain in [0, 1] (default: disabled,
+    /// i.e., pure open-loop IAT sleeps)
+    #[argh(option)]
+    pacing_gain: Option<f64>,
+
+    /// HOST:PORT address to serve Prometheus `/metrics` on (default: disabled)
+    #[argh(option)]
+    metrics_addr: Option<SocketAddr>,
+
+    /// enable the closed-loop throughput tranquilizer with the given aggregate target
+    /// requests-per-second across all Workers (default: disabled)
+    #[argh(option)]
+    tranquilizer_target_rps: Option<f64>,
+    /// EWMA smoothing factor in [0, 1] for the tranquilizer's achieved-throughput estimate
+    #[argh(option, default = "0.2")]
+    tranquilizer_smoothing: f64,
+
+    /// fire each minute's invocations on a deadline-driven controller that tracks achieved vs.
+    /// target RPM, instead of sleeping through the open-loop IAT stream (default: disabled)
+    #[argh(switch)]
+    rpm_controller: bool,
+
+    /// seconds to wait for Workers to drain cooperatively on shutdown before forcefully
+    /// aborting whichever ones remain
+    #[argh(option, default = "10")]
+    drain_grace_secs: u64,
+
+    /// max attempts per invocation against the backend, including the first (default: 1, i.e.,
+    /// retries disabled)
+    #[argh(option, default = "1")]
+    retry_max_attempts: u32,
+
+    /// hard per-attempt timeout in milliseconds, enforced on top of whatever's left of the
+    /// current minute
+    #[argh(option, default = "30_000")]
+    retry_attempt_timeout_ms: u64,
+
+    /// base delay in milliseconds for the exponential backoff between retry attempts
+    #[argh(option, default = "100")]
+    retry_base_delay_ms: u64,
+
+    /// cap in milliseconds on the backoff delay between retry attempts
+    #[argh(option, default = "5_000")]
+    retry_max_delay_ms: u64,
+
+    /// log requests as length-delimited MessagePack frames instead of newline-delimited JSON
+    /// (default: disabled, i.e., JSON-lines)
+    #[argh(switch)]
+    msgpack_log: bool,
 }
 
-fn setup_signals_handler(shutdown: broadcast::Sender<()>) -> Result<()> {
+fn setup_signals_handler(shutdown: CancellationToken) -> Result<()> {
     let mut signals = [
         ("ALRM", signal(SignalKind::alarm())),
         ("HUP", signal(SignalKind::hangup())),
@@ -86,11 +137,8 @@ fn setup_signals_handler(shutdown: broadcast::Sender<()>) -> Result<()> {
 
     let _h = ::tokio::spawn(async move {
         while signals.next().await.is_some() {
-            warn!("Signal received; sending shutdown notification");
-            if let Err(err) = shutdown.send(()) {
-                error!(error = ?err, "Failed to send shutdown notification!");
-                panic!("failed to send shutdown notification: {err:#}");
-            }
+            warn!("Signal received; requesting shutdown");
+            shutdown.cancel();
         }
     });
 
@@ -112,18 +160,31 @@ async fn main() -> Result<()> {
     let cli = ::argh::from_env::<Cli>();
     trace!("{cli:?}");
 
-    let (shutdown, _) = broadcast::channel(1);
+    let shutdown = CancellationToken::new();
     setup_signals_handler(shutdown.clone())?;
 
-    let sink_backend = NoOpSink::<NoResponse>::default();
-    let sink_client =
-        SinkClient::new(&cli.outfile, sink_backend).context("failed to create Sink client")?;
+    let metrics = cli.metrics_addr.map(|_| MetricsRegistry::new());
+    if let (Some(metrics), Some(addr)) = (metrics.clone(), cli.metrics_addr) {
+        let shutdown = shutdown.clone();
+        ::tokio::spawn(async move {
+            if let Err(err) = metrics.serve(addr, shutdown).await {
+                error!(error = ?err, "Metrics server exited with an error: {err:#}");
+            }
+        });
+    }
+
+    let mut sink_client =
+        SinkClient::new(&cli.outfile, NoOpSink::<NoResponse>::default())
+            .context("failed to create Sink client")?;
+    if let Some(ref metrics) = metrics {
+        sink_client = sink_client.with_metrics(metrics.clone());
+    }
     let sink = ::tokio::spawn({
-        let shutdown = shutdown.subscribe();
+        let shutdown = shutdown.clone();
         async move { sink_client.run(shutdown).await }
     });
 
-    let source_backend = Logger::new(BufWriter::new(
+    let requests_file = BufWriter::new(
         File::options()
             .create_new(true)
             .write(true)
@@ -134,7 +195,12 @@ async fn main() -> Result<()> {
                     cli.requests.display()
                 )
             })?,
-    ));
+    );
+    let source_backend = if cli.msgpack_log {
+        Logger::with_codec(requests_file, Box::new(LengthDelimitedMsgPackCodec))
+    } else {
+        Logger::with_codec(requests_file, Box::new(JsonLinesCodec))
+    };
     let mut source_client = SourceClient::new(
         &cli.csv,
         None::<&str>,
@@ -145,20 +211,32 @@ async fn main() -> Result<()> {
         source_backend
             .new_ref()
             .expect("Logger has not been run yet"),
+        RetryPolicy {
+            max_attempts: cli.retry_max_attempts,
+            attempt_timeout: Duration::from_millis(cli.retry_attempt_timeout_ms),
+            base_delay: Duration::from_millis(cli.retry_base_delay_ms),
+            max_delay: Duration::from_millis(cli.retry_max_delay_ms),
+        },
         &cli.minio_address,
         &cli.minio_bucket,
+        cli.pacing_gain,
+        cli.tranquilizer_target_rps,
+        cli.tranquilizer_smoothing,
+        cli.rpm_controller,
+        metrics,
+        Duration::from_secs(cli.drain_grace_secs),
     )
     .context("failed to create Source client")?;
     let logger = ::tokio::task::spawn_blocking(move || source_backend.run());
     let source = ::tokio::spawn({
-        let shutdown = shutdown.subscribe();
+        let shutdown = shutdown.clone();
         async move { source_client.run(shutdown).await }
     });
 
     match ::tokio::try_join!(source, logger, sink) {
         Ok((source, logger, sink)) => {
             match source {
-                Ok(num_requests) => info!(?num_requests, "Source task joined"),
+                Ok(statuses) => info!(?statuses, "Source task joined"),
                 Err(err) => error!(error = ?err, "Joined failed Source task: {err:#}"),
             }
             match logger {
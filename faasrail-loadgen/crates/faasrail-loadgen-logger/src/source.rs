@@ -1,51 +1,37 @@
 use std::{
     convert::Infallible,
-    fmt::Debug,
-    io::{self, Write},
+    io::Write,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{error, info, instrument, Level};
 
-use faasrail_loadgen::{source::SourceBackend, InvocationId, WorkloadRequest};
-
-#[derive(Debug, ::thiserror::Error)]
-pub enum Error {
-    #[error("JSON serialization error")]
-    JsonSerialization(#[source] ::serde_json::Error),
-
-    #[error("I/O Error: {msg}")]
-    Io {
-        msg: Box<str>,
-        #[source]
-        err: io::Error,
-    },
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct LoggedRequest {
-    epoch_us: u64,
-    invocation_id: InvocationId,
-    wreq: WorkloadRequest,
-}
+pub use faasrail_loadgen::codec::{Codec, Error, JsonLinesCodec, LengthDelimitedMsgPackCodec};
+use faasrail_loadgen::{codec::TraceEntry, source::SourceBackend, InvocationId, WorkloadRequest};
 
 #[derive(Debug)]
 pub struct Logger<W: Write> {
     writer: W,
+    codec: Box<dyn Codec>,
 
-    tx: Option<mpsc::Sender<LoggedRequest>>,
-    rx: mpsc::Receiver<LoggedRequest>,
+    tx: Option<mpsc::Sender<TraceEntry>>,
+    rx: mpsc::Receiver<TraceEntry>,
 }
 
 impl<W: Write> Logger<W> {
     const BUFSZ: usize = 1 << 15;
 
+    /// Logs as newline-delimited JSON (via [`JsonLinesCodec`]).
     pub fn new(inner: W) -> Self {
+        Self::with_codec(inner, Box::new(JsonLinesCodec))
+    }
+
+    pub fn with_codec(inner: W, codec: Box<dyn Codec>) -> Self {
         let (tx, rx) = mpsc::channel(Self::BUFSZ);
         Self {
             writer: inner,
+            codec,
             tx: Some(tx),
             rx,
         }
@@ -65,14 +51,9 @@ impl<W: Write> Logger<W> {
         drop(tx);
 
         let mut num_requests = 0;
-        while let Some(lreq) = self.rx.blocking_recv() {
+        while let Some(entry) = self.rx.blocking_recv() {
             num_requests += 1;
-
-            ::serde_json::to_writer(&mut self.writer, &lreq).map_err(Error::JsonSerialization)?;
-            self.writer.write_all(b"\n").map_err(|err| Error::Io {
-                msg: "error apending newline to writer".into(),
-                err,
-            })?;
+            self.codec.encode(&entry, &mut self.writer)?;
         }
         info!("Exiting...");
         Ok(num_requests)
@@ -81,7 +62,7 @@ impl<W: Write> Logger<W> {
 
 #[derive(Debug, Clone)]
 pub struct LoggerRef {
-    tx: mpsc::Sender<LoggedRequest>,
+    tx: mpsc::Sender<TraceEntry>,
 }
 
 impl SourceBackend for LoggerRef {
@@ -99,7 +80,7 @@ impl SourceBackend for LoggerRef {
         if let Err(err) = self
             .tx
             .send_timeout(
-                LoggedRequest {
+                TraceEntry {
                     epoch_us: SystemTime::now()
                         .duration_since(UNIX_EPOCH)
                         .expect("UNIX Epoch should be < than all timestamps")
@@ -0,0 +1,227 @@
+use std::{
+    fmt::Debug,
+    io::{self, BufRead, Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{InvocationId, WorkloadRequest};
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum Error {
+    #[error("JSON serialization error")]
+    JsonSerialization(#[source] ::serde_json::Error),
+
+    #[error("JSON deserialization error")]
+    JsonDeserialization(#[source] ::serde_json::Error),
+
+    #[error("MessagePack serialization error")]
+    MsgPackSerialization(#[source] ::rmp_serde::encode::Error),
+
+    #[error("MessagePack deserialization error")]
+    MsgPackDeserialization(#[source] ::rmp_serde::decode::Error),
+
+    #[error("encoded TraceEntry frame of {len} bytes exceeds the u32 length-prefix limit")]
+    FrameTooLarge { len: usize },
+
+    #[error("I/O error: {msg}")]
+    Io {
+        msg: Box<str>,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// One recorded invocation: the wall-clock microsecond timestamp it was originally issued at,
+/// alongside its [`InvocationId`] and full [`WorkloadRequest`]. This is the unit [`Codec`]
+/// encodes/decodes, both when `faasrail-loadgen-logger`'s `Logger` writes a trace log and when
+/// [`TraceReplayWorker`](crate::source::worker::TraceReplayWorker) reads one back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct TraceEntry {
+    pub epoch_us: u64,
+    pub invocation_id: InvocationId,
+    pub wreq: WorkloadRequest,
+}
+
+/// Serializes/deserializes a single [`TraceEntry`] to/from a trace log. Pluggable so neither the
+/// writer (`Logger`) nor the reader ([`TraceReplayWorker`](crate::source::worker::TraceReplayWorker))
+/// is hard-wired to newline-delimited JSON: a multi-hour trace produces millions of rows, and a
+/// length-delimited binary frame both shrinks the file and makes it seekable without
+/// line-scanning. Modeled on `tokio_util::codec` encoders/decoders like
+/// `LengthDelimitedCodec`/`LinesCodec`, just `Write`/`BufRead`- rather than `BytesMut`-based,
+/// since both call sites work with blocking I/O rather than a framed `Sink`/`Stream`.
+///
+/// Both the writer and reader of a given trace log must agree on which `Codec` it was written
+/// with; nothing in the log itself identifies the format.
+pub trait Codec: Debug + Send {
+    fn encode(&mut self, entry: &TraceEntry, writer: &mut dyn Write) -> Result<(), Error>;
+
+    /// Reads the next entry, or `Ok(None)` once the log is cleanly exhausted.
+    fn decode(&mut self, reader: &mut dyn BufRead) -> Result<Option<TraceEntry>, Error>;
+}
+
+/// The original format: one JSON-encoded [`TraceEntry`] per line.
+#[derive(Debug, Default)]
+pub struct JsonLinesCodec;
+
+impl Codec for JsonLinesCodec {
+    fn encode(&mut self, entry: &TraceEntry, writer: &mut dyn Write) -> Result<(), Error> {
+        ::serde_json::to_writer(&mut *writer, entry).map_err(Error::JsonSerialization)?;
+        writer.write_all(b"\n").map_err(|err| Error::Io {
+            msg: "error appending newline to writer".into(),
+            source: err,
+        })
+    }
+
+    fn decode(&mut self, reader: &mut dyn BufRead) -> Result<Option<TraceEntry>, Error> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let num_bytes = reader.read_line(&mut line).map_err(|err| Error::Io {
+                msg: "error reading line from reader".into(),
+                source: err,
+            })?;
+            if num_bytes == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                continue;
+            }
+            return ::serde_json::from_str(line).map(Some).map_err(Error::JsonDeserialization);
+        }
+    }
+}
+
+/// Frames each [`TraceEntry`] as MessagePack, prefixed with its encoded length as a big-endian
+/// `u32`, for a smaller, line-scan-free log.
+#[derive(Debug, Default)]
+pub struct LengthDelimitedMsgPackCodec;
+
+/// Checks that `len` (an encoded payload's byte length) fits the `u32` length-prefix, split out
+/// from [`LengthDelimitedMsgPackCodec::encode`] so the overflow path is testable without actually
+/// allocating a multi-gigabyte payload.
+fn checked_frame_len(len: usize) -> Result<u32, Error> {
+    u32::try_from(len).map_err(|_| Error::FrameTooLarge { len })
+}
+
+impl Codec for LengthDelimitedMsgPackCodec {
+    fn encode(&mut self, entry: &TraceEntry, writer: &mut dyn Write) -> Result<(), Error> {
+        // Structs are encoded as maps (not positional arrays), so `WorkloadRequest`'s skipped
+        // non-finite fields don't shift the remaining ones on decode.
+        let payload = ::rmp_serde::to_vec_named(entry).map_err(Error::MsgPackSerialization)?;
+        let len = checked_frame_len(payload.len())?;
+        writer.write_all(&len.to_be_bytes()).map_err(|err| Error::Io {
+            msg: "error writing frame length prefix".into(),
+            source: err,
+        })?;
+        writer.write_all(&payload).map_err(|err| Error::Io {
+            msg: "error writing MessagePack frame".into(),
+            source: err,
+        })
+    }
+
+    fn decode(&mut self, reader: &mut dyn BufRead) -> Result<Option<TraceEntry>, Error> {
+        let mut len_buf = [0u8; 4];
+        if let Err(err) = reader.read_exact(&mut len_buf) {
+            return if err.kind() == io::ErrorKind::UnexpectedEof {
+                Ok(None)
+            } else {
+                Err(Error::Io { msg: "error reading frame length prefix".into(), source: err })
+            };
+        }
+        let mut payload = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        reader.read_exact(&mut payload).map_err(|err| Error::Io {
+            msg: "error reading MessagePack frame".into(),
+            source: err,
+        })?;
+        ::rmp_serde::from_slice(&payload).map(Some).map_err(Error::MsgPackDeserialization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{
+        checked_frame_len, Codec, Error, JsonLinesCodec, LengthDelimitedMsgPackCodec, TraceEntry,
+    };
+    use crate::WorkloadRequest;
+
+    fn sample_entry() -> TraceEntry {
+        TraceEntry {
+            epoch_us: 1_234_567_890,
+            invocation_id: "test-invocation-id".into(),
+            wreq: ::serde_json::from_str::<WorkloadRequest>(
+                r#"{"bench":"test-bench","payload":"test-payload"}"#,
+            )
+            .expect("valid WorkloadRequest JSON"),
+        }
+    }
+
+    #[test]
+    fn json_lines_roundtrip() {
+        let entry = sample_entry();
+        let mut buf = Vec::new();
+        JsonLinesCodec.encode(&entry, &mut buf).expect("encode should succeed");
+
+        let mut cursor = Cursor::new(buf);
+        let decoded = JsonLinesCodec
+            .decode(&mut cursor)
+            .expect("decode should succeed")
+            .expect("should decode one entry");
+        assert_eq!(entry, decoded);
+        assert!(JsonLinesCodec
+            .decode(&mut cursor)
+            .expect("decode at EOF should succeed")
+            .is_none());
+    }
+
+    #[test]
+    fn json_lines_skips_blank_lines() {
+        let entry = sample_entry();
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"\n\n");
+        JsonLinesCodec.encode(&entry, &mut buf).expect("encode should succeed");
+        buf.extend_from_slice(b"\n");
+
+        let mut cursor = Cursor::new(buf);
+        let decoded = JsonLinesCodec
+            .decode(&mut cursor)
+            .expect("decode should succeed")
+            .expect("should skip blank lines and decode the entry");
+        assert_eq!(entry, decoded);
+        assert!(JsonLinesCodec
+            .decode(&mut cursor)
+            .expect("decode at clean EOF should succeed")
+            .is_none());
+    }
+
+    #[test]
+    fn msgpack_roundtrip() {
+        let entry = sample_entry();
+        let mut buf = Vec::new();
+        LengthDelimitedMsgPackCodec
+            .encode(&entry, &mut buf)
+            .expect("encode should succeed");
+
+        let mut cursor = Cursor::new(buf);
+        let decoded = LengthDelimitedMsgPackCodec
+            .decode(&mut cursor)
+            .expect("decode should succeed")
+            .expect("should decode one entry");
+        assert_eq!(entry, decoded);
+        assert!(LengthDelimitedMsgPackCodec
+            .decode(&mut cursor)
+            .expect("decode at clean EOF should succeed")
+            .is_none());
+    }
+
+    #[test]
+    fn msgpack_frame_too_large() {
+        let err = checked_frame_len(u32::MAX as usize + 1).unwrap_err();
+        assert!(matches!(err, Error::FrameTooLarge { len } if len == u32::MAX as usize + 1));
+        assert!(checked_frame_len(u32::MAX as usize).is_ok());
+    }
+}
@@ -5,7 +5,9 @@
     //rustdoc::all,
 )]
 
+pub mod codec;
 pub mod fixer;
+pub mod metrics;
 pub mod sink;
 pub mod source;
 mod wreq;
@@ -0,0 +1,339 @@
+//! A small Prometheus text-exposition-format metrics subsystem for observing fidelity (achieved
+//! load vs. target) while a run is in flight, rather than only at task-join time.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use compact_str::CompactString;
+use tokio::{io::AsyncWriteExt, net::TcpListener};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn, Level};
+
+/// Exponential latency-histogram bucket upper bounds, in milliseconds (1ms .. ~60s).
+pub(crate) const LATENCY_BUCKETS_MS: &[f64] = &[
+    1., 2., 4., 8., 16., 32., 64., 128., 256., 512., 1024., 2048., 4096., 8192., 16384., 32768.,
+    60_000.,
+];
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum Error {
+    #[error("failed to bind metrics HTTP listener on {addr}")]
+    Bind {
+        addr: SocketAddr,
+        #[source]
+        source: ::std::io::Error,
+    },
+}
+
+#[derive(Debug)]
+struct FunctionMetrics {
+    intended_rpm: AtomicU64,
+    achieved_this_minute: AtomicU64,
+    drift_us: AtomicI64,
+    invocations_total: AtomicU64,
+    failures_total: AtomicU64,
+    in_flight: AtomicI64,
+    latency_bucket_counts: Mutex<Vec<u64>>,
+    latency_sum_ms: AtomicU64,
+    latency_count: AtomicU64,
+}
+
+impl Default for FunctionMetrics {
+    fn default() -> Self {
+        Self {
+            intended_rpm: AtomicU64::default(),
+            achieved_this_minute: AtomicU64::default(),
+            drift_us: AtomicI64::default(),
+            invocations_total: AtomicU64::default(),
+            failures_total: AtomicU64::default(),
+            in_flight: AtomicI64::default(),
+            latency_bucket_counts: Mutex::new(vec![0; LATENCY_BUCKETS_MS.len()]),
+            latency_sum_ms: AtomicU64::default(),
+            latency_count: AtomicU64::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    per_function: Mutex<BTreeMap<CompactString, Arc<FunctionMetrics>>>,
+    appender_depth: AtomicU64,
+    appender_capacity: AtomicU64,
+}
+
+/// Shared, cheaply-clonable handle to the process' live metrics, exposed over HTTP in Prometheus
+/// text exposition format.
+///
+/// Per function (`bench`/`mapped_wreq`), this tracks intended RPM vs. achieved RPM per minute,
+/// emission drift, invocation/failure counters, in-flight invocations and response-latency
+/// histograms; process-wide, it tracks the sink's appender `mpsc` channel depth/backpressure.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsRegistry {
+    inner: Arc<Inner>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn function(&self, bench: &str) -> Arc<FunctionMetrics> {
+        let mut per_function = self.inner.per_function.lock().expect("poisoned");
+        Arc::clone(
+            per_function
+                .entry(CompactString::from(bench))
+                .or_default(),
+        )
+    }
+
+    /// Records the intended (target) RPM for `bench` at the start of a new minute, resetting its
+    /// achieved-this-minute counter.
+    pub(crate) fn start_minute(&self, bench: &str, rpm: u32) {
+        let fm = self.function(bench);
+        fm.intended_rpm.store(u64::from(rpm), Ordering::Relaxed);
+        fm.achieved_this_minute.store(0, Ordering::Relaxed);
+    }
+
+    /// Records one successfully-issued invocation for `bench` in the current minute.
+    pub(crate) fn record_issued(&self, bench: &str) {
+        let fm = self.function(bench);
+        fm.achieved_this_minute.fetch_add(1, Ordering::Relaxed);
+        fm.invocations_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records one failed invocation attempt for `bench` (does not count towards achieved RPM).
+    pub(crate) fn record_failure(&self, bench: &str) {
+        let fm = self.function(bench);
+        fm.invocations_total.fetch_add(1, Ordering::Relaxed);
+        fm.failures_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Adjusts `bench`'s in-flight gauge by `delta` (`+1` when an invocation starts, `-1` when
+    /// it completes, successfully or not).
+    pub(crate) fn adjust_in_flight(&self, bench: &str, delta: i64) {
+        self.function(bench)
+            .in_flight
+            .fetch_add(delta, Ordering::Relaxed);
+    }
+
+    /// Records how far behind (positive) or ahead (negative) of the intended schedule `bench`'s
+    /// emission currently is.
+    pub(crate) fn record_drift(&self, bench: &str, drift_secs: f64) {
+        self.function(bench)
+            .drift_us
+            .store((drift_secs * 1e6) as i64, Ordering::Relaxed);
+    }
+
+    /// Records one observed end-to-end response latency for `bench`. Intended to be called by
+    /// [`Backend`](crate::sink::SinkBackend) implementations as `Response`s arrive.
+    pub fn record_latency(&self, bench: &str, latency: Duration) {
+        let fm = self.function(bench);
+        let ms = latency.as_secs_f64() * 1e3;
+        // Latencies above the last bound only count towards the `+Inf` bucket (`latency_count`).
+        if let Some(bucket) = LATENCY_BUCKETS_MS.iter().position(|&upper| ms <= upper) {
+            fm.latency_bucket_counts.lock().expect("poisoned")[bucket] += 1;
+        }
+        fm.latency_sum_ms.fetch_add(ms as u64, Ordering::Relaxed);
+        fm.latency_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records the current depth/capacity of the sink's appender `mpsc` channel.
+    pub fn set_appender_depth(&self, depth: usize, capacity: usize) {
+        self.inner
+            .appender_depth
+            .store(depth as u64, Ordering::Relaxed);
+        self.inner
+            .appender_capacity
+            .store(capacity as u64, Ordering::Relaxed);
+    }
+
+    /// Renders all metrics in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let per_function = self.inner.per_function.lock().expect("poisoned");
+
+        let _ = writeln!(out, "# HELP faasrail_intended_rpm Target requests-per-minute.");
+        let _ = writeln!(out, "# TYPE faasrail_intended_rpm gauge");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_intended_rpm{{bench=\"{bench}\"}} {}",
+                fm.intended_rpm.load(Ordering::Relaxed),
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_achieved_rpm Requests actually issued so far this minute."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_achieved_rpm gauge");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_achieved_rpm{{bench=\"{bench}\"}} {}",
+                fm.achieved_this_minute.load(Ordering::Relaxed),
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_emission_drift_seconds Seconds behind (positive) or ahead \
+             (negative) of the intended schedule."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_emission_drift_seconds gauge");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_emission_drift_seconds{{bench=\"{bench}\"}} {:.6}",
+                fm.drift_us.load(Ordering::Relaxed) as f64 / 1e6,
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_invocations_total Total invocations attempted for this function."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_invocations_total counter");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_invocations_total{{bench=\"{bench}\"}} {}",
+                fm.invocations_total.load(Ordering::Relaxed),
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_failures_total Total failed invocation attempts for this function."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_failures_total counter");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_failures_total{{bench=\"{bench}\"}} {}",
+                fm.failures_total.load(Ordering::Relaxed),
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_in_flight Invocations currently awaiting a response."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_in_flight gauge");
+        for (bench, fm) in per_function.iter() {
+            let _ = writeln!(
+                out,
+                "faasrail_in_flight{{bench=\"{bench}\"}} {}",
+                fm.in_flight.load(Ordering::Relaxed),
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_appender_channel_depth Current depth of the sink's appender mpsc \
+             channel."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_appender_channel_depth gauge");
+        let _ = writeln!(
+            out,
+            "faasrail_appender_channel_depth {}",
+            self.inner.appender_depth.load(Ordering::Relaxed),
+        );
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_appender_channel_capacity Configured capacity of the sink's \
+             appender mpsc channel."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_appender_channel_capacity gauge");
+        let _ = writeln!(
+            out,
+            "faasrail_appender_channel_capacity {}",
+            self.inner.appender_capacity.load(Ordering::Relaxed),
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP faasrail_response_latency_ms Response latency, in milliseconds."
+        );
+        let _ = writeln!(out, "# TYPE faasrail_response_latency_ms histogram");
+        for (bench, fm) in per_function.iter() {
+            let buckets = fm.latency_bucket_counts.lock().expect("poisoned");
+            let mut cumulative = 0u64;
+            for (&upper, &count) in LATENCY_BUCKETS_MS.iter().zip(buckets.iter()) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "faasrail_response_latency_ms_bucket{{bench=\"{bench}\",le=\"{upper}\"}} \
+                     {cumulative}",
+                );
+            }
+            let _ = writeln!(
+                out,
+                "faasrail_response_latency_ms_bucket{{bench=\"{bench}\",le=\"+Inf\"}} {}",
+                fm.latency_count.load(Ordering::Relaxed),
+            );
+            let _ = writeln!(
+                out,
+                "faasrail_response_latency_ms_sum{{bench=\"{bench}\"}} {}",
+                fm.latency_sum_ms.load(Ordering::Relaxed),
+            );
+            let _ = writeln!(
+                out,
+                "faasrail_response_latency_ms_count{{bench=\"{bench}\"}} {}",
+                fm.latency_count.load(Ordering::Relaxed),
+            );
+        }
+
+        out
+    }
+
+    /// Serves this registry's [`render`](Self::render) output over HTTP at `/metrics`, in
+    /// Prometheus text exposition format, until `cancel` fires.
+    #[instrument(level = Level::INFO, skip_all, fields(%addr))]
+    pub async fn serve(self, addr: SocketAddr, cancel: CancellationToken) -> Result<(), Error> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|source| Error::Bind { addr, source })?;
+        info!("Serving Prometheus metrics");
+
+        loop {
+            ::tokio::select! {
+                biased;
+
+                () = cancel.cancelled() => {
+                    info!("Received cancellation notification; shutting down");
+                    return Ok(());
+                }
+
+                accepted = listener.accept() => {
+                    let (mut stream, peer) = match accepted {
+                        Ok(pair) => pair,
+                        Err(err) => {
+                            warn!(error = ?err, "Failed to accept metrics connection");
+                            continue;
+                        }
+                    };
+                    let body = self.render();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\n\
+                         Content-Type: text/plain; version=0.0.4\r\n\
+                         Content-Length: {}\r\n\
+                         Connection: close\r\n\r\n{body}",
+                        body.len(),
+                    );
+                    if let Err(err) = stream.write_all(response.as_bytes()).await {
+                        debug!(error = ?err, %peer, "Failed to write metrics response");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use compact_str::CompactString;
+use serde::Serialize;
+use tokio::{
+    sync::{mpsc, Semaphore},
+    task::JoinSet,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, instrument, warn, Level};
+
+use crate::{metrics::MetricsRegistry, source::SourceClient, WorkloadRequest};
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum Error {
+    #[error("failed to parse input CSV file")]
+    Csv(#[source] crate::source::Error),
+
+    #[error("JSON deserialization error: {msg}")]
+    Deserialization {
+        msg: Box<str>,
+        #[source]
+        source: ::serde_json::Error,
+    },
+
+    #[error("failed to adjust the payload of {wreq:?}")]
+    FbpmlPayloadFix {
+        wreq: Box<WorkloadRequest>,
+        #[source]
+        source: crate::fixer::Error,
+    },
+}
+
+/// One observed HTTP response to an invoked [`WorkloadRequest`].
+#[derive(Debug, Clone, Serialize)]
+pub struct HttpResponse {
+    pub bench: CompactString,
+    /// `None` if the request timed out or otherwise failed at the transport level.
+    pub status: Option<u16>,
+    pub latency_ms: u64,
+}
+
+/// Invokes every [`WorkloadRequest`] read from a CSV file against a live FaaS gateway over HTTP,
+/// bounding in-flight concurrency and per-request time, and forwards the observed status/latency
+/// as an [`HttpResponse`] down `to_appender`. This turns FaaSRAIL from a schedule logger into an
+/// actual closed-loop load generator against a live serverless platform.
+///
+/// This is a library-only extension point: `faasrail-loadgen-logger`'s CLI always drives
+/// [`SinkClient`](crate::sink::SinkClient) with `NoOp`, so exercising `Http` currently means
+/// embedding `faasrail-loadgen` directly rather than invoking the shipped binary.
+#[derive(Debug)]
+pub struct Http {
+    csv_path: PathBuf,
+    /// Invocation-endpoint template; the literal substring `{bench}` is replaced with the
+    /// function's `bench` name.
+    endpoint_template: String,
+    minio_address: CompactString,
+    bucket_name: CompactString,
+    concurrency: usize,
+    timeout: Duration,
+    client: ::reqwest::Client,
+    metrics: Option<MetricsRegistry>,
+}
+
+impl Http {
+    pub fn new(
+        csv_path: impl Into<PathBuf>,
+        endpoint_template: impl Into<String>,
+        minio_address: impl Into<CompactString>,
+        bucket_name: impl Into<CompactString>,
+        concurrency: usize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            csv_path: csv_path.into(),
+            endpoint_template: endpoint_template.into(),
+            minio_address: minio_address.into(),
+            bucket_name: bucket_name.into(),
+            concurrency: concurrency.max(1),
+            timeout,
+            client: ::reqwest::Client::new(),
+            metrics: None,
+        }
+    }
+
+    /// Attaches a [`MetricsRegistry`] so each response's latency is recorded into the
+    /// `faasrail_response_latency_ms` histogram, alongside the appender channel stats
+    /// [`SinkClient::with_metrics`](crate::sink::SinkClient::with_metrics) reports.
+    pub fn with_metrics(mut self, metrics: MetricsRegistry) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    fn load_wreqs(&self) -> Result<Vec<WorkloadRequest>, Error> {
+        SourceClient::parse_csv(&self.csv_path)
+            .map_err(Error::Csv)?
+            .into_iter()
+            .map(|row| {
+                let mut wreq = ::serde_json::from_str::<WorkloadRequest>(&row.mapped_wreq)
+                    .map_err(|err| Error::Deserialization {
+                        msg: format!("mapped WorkloadRequest: {:?}", row.mapped_wreq)
+                            .into_boxed_str(),
+                        source: err,
+                    })?;
+                crate::fixer::fix_fbpml_payload(&mut wreq, &self.minio_address, &self.bucket_name)
+                    .map_err(|source| Error::FbpmlPayloadFix {
+                        wreq: Box::new(wreq.clone()),
+                        source,
+                    })?;
+                Ok(wreq)
+            })
+            .collect()
+    }
+}
+
+impl super::Backend for Http {
+    type Error = Error;
+    type Response = HttpResponse;
+
+    #[instrument(level = Level::INFO, skip_all)]
+    async fn run(
+        self,
+        to_appender: mpsc::Sender<Self::Response>,
+        cancel: CancellationToken,
+    ) -> Result<u64, Self::Error> {
+        let wreqs = self.load_wreqs()?;
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let mut tasks = JoinSet::new();
+
+        let mut quit = false;
+        for wreq in wreqs {
+            if !quit && cancel.is_cancelled() {
+                warn!("Received cancellation notification; no further requests will be issued");
+                quit = true;
+            }
+            if quit {
+                break;
+            }
+
+            let permit = Arc::clone(&semaphore)
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let client = self.client.clone();
+            let endpoint = self.endpoint_template.replace("{bench}", wreq.bench.as_str());
+            let to_appender = to_appender.clone();
+            let timeout = self.timeout;
+            let bench = wreq.bench.clone();
+            let metrics = self.metrics.clone();
+
+            tasks.spawn(async move {
+                let _permit = permit;
+                let t_start = Instant::now();
+                let (status, latency_ms) =
+                    match ::tokio::time::timeout(timeout, client.post(&endpoint).json(&wreq).send())
+                        .await
+                    {
+                        Ok(Ok(resp)) => {
+                            (Some(resp.status().as_u16()), t_start.elapsed().as_millis() as u64)
+                        }
+                        Ok(Err(err)) => {
+                            warn!(error = ?err, %endpoint, "HTTP request failed");
+                            (None, t_start.elapsed().as_millis() as u64)
+                        }
+                        Err(_elapsed) => {
+                            warn!(%endpoint, ?timeout, "HTTP request timed out");
+                            (None, timeout.as_millis() as u64)
+                        }
+                    };
+                if let Some(ref metrics) = metrics {
+                    metrics.record_latency(&bench, Duration::from_millis(latency_ms));
+                }
+                if let Err(err) = to_appender
+                    .send(HttpResponse { bench, status, latency_ms })
+                    .await
+                {
+                    error!(error = ?err, "Failed to forward HttpResponse to appender");
+                }
+            });
+        }
+
+        // Drain all outstanding in-flight requests rather than aborting them.
+        let mut num_issued = 0u64;
+        while tasks.join_next().await.is_some() {
+            num_issued += 1;
+        }
+        info!(?num_issued, "All in-flight requests drained; exiting...");
+        Ok(num_issued)
+    }
+}
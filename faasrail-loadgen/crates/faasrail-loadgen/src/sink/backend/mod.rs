@@ -1,11 +1,19 @@
+mod http;
+mod nats;
 mod noop;
+pub use http::Error as HttpError;
+pub use http::Http;
+pub use http::HttpResponse;
+pub use nats::Error as NatsError;
+pub use nats::Nats;
 pub use noop::NoOp;
 pub use noop::NoResponse;
 
 use std::{error::Error as stdError, fmt::Debug, future::Future};
 
 use serde::Serialize;
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 pub trait Backend: Debug + Send + 'static {
     type Error: stdError + Send + Sync + 'static;
@@ -14,6 +22,6 @@ pub trait Backend: Debug + Send + 'static {
     fn run(
         self,
         to_appender: mpsc::Sender<Self::Response>,
-        quit_rx: broadcast::Receiver<()>,
+        cancel: CancellationToken,
     ) -> impl Future<Output = Result<u64, Self::Error>> + Send;
 }
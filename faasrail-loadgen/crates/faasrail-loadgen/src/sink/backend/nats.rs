@@ -0,0 +1,186 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use compact_str::CompactString;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, instrument, warn, Level};
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum Error {
+    #[error("failed to connect to NATS server at {server_url}")]
+    Connect {
+        server_url: CompactString,
+        #[source]
+        source: ::async_nats::ConnectError,
+    },
+
+    #[error("failed to bind to JetStream stream {stream}")]
+    JetStreamBind {
+        stream: CompactString,
+        #[source]
+        source: ::async_nats::jetstream::context::CreateStreamError,
+    },
+}
+
+/// Publishes each `Response` it receives to a NATS subject, optionally via JetStream for
+/// durable, multi-consumer persistence, instead of (or alongside) the local JSON-lines file.
+/// This fans responses from multiple FaaSRAIL load generators into a single message bus for
+/// aggregation during large multi-node experiments.
+#[derive(Debug)]
+pub struct Nats<Resp> {
+    from_source: mpsc::Receiver<Resp>,
+    server_url: CompactString,
+    subject_prefix: CompactString,
+    stream_name: Option<CompactString>,
+    forward_to_file: bool,
+    _phantom: PhantomData<fn() -> Resp>,
+}
+
+impl<Resp> Nats<Resp> {
+    /// `stream_name`, if given, makes publishing go through JetStream (bound to `{subject_prefix}`
+    /// and `{subject_prefix}.>`) for durability, rather than NATS core pub/sub. Set
+    /// `forward_to_file` to also forward every `Response` to the local appender file.
+    pub fn new(
+        from_source: mpsc::Receiver<Resp>,
+        server_url: impl Into<CompactString>,
+        subject_prefix: impl Into<CompactString>,
+        stream_name: Option<CompactString>,
+        forward_to_file: bool,
+    ) -> Self {
+        Self {
+            from_source,
+            server_url: server_url.into(),
+            subject_prefix: subject_prefix.into(),
+            stream_name,
+            forward_to_file,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Publishes `payload` to `subject`, returning whether the publish itself succeeded (errors
+    /// are logged either way, not propagated, so one bad `Response` cannot sink the whole run).
+    /// Note that for core NATS pub/sub (`jetstream.is_none()`), success only means the message was
+    /// accepted by the client's outbound buffer, not that any subscriber received it.
+    async fn publish(
+        subject: &str,
+        client: &::async_nats::Client,
+        jetstream: Option<&::async_nats::jetstream::Context>,
+        payload: Vec<u8>,
+    ) -> bool {
+        let result = if let Some(js) = jetstream {
+            js.publish(subject.to_string(), payload.into())
+                .await
+                .map(|_ack| ())
+        } else {
+            client.publish(subject.to_string(), payload.into()).await
+        };
+        match result {
+            Ok(()) => true,
+            Err(err) => {
+                error!(error = ?err, %subject, "Failed to publish Response to NATS");
+                false
+            }
+        }
+    }
+}
+
+impl<Resp> super::Backend for Nats<Resp>
+where
+    Resp: Serialize + Send + Debug + 'static,
+{
+    type Error = Error;
+    type Response = Resp;
+
+    #[instrument(level = Level::INFO, skip_all)]
+    async fn run(
+        mut self,
+        to_appender: mpsc::Sender<Self::Response>,
+        cancel: CancellationToken,
+    ) -> Result<u64, Self::Error> {
+        let client =
+            ::async_nats::connect(self.server_url.as_str())
+                .await
+                .map_err(|source| Error::Connect {
+                    server_url: self.server_url.clone(),
+                    source,
+                })?;
+
+        let jetstream = match &self.stream_name {
+            Some(stream) => {
+                let js = ::async_nats::jetstream::new(client.clone());
+                js.get_or_create_stream(::async_nats::jetstream::stream::Config {
+                    name: stream.to_string(),
+                    subjects: vec![
+                        self.subject_prefix.to_string(),
+                        format!("{}.>", self.subject_prefix),
+                    ],
+                    ..Default::default()
+                })
+                .await
+                .map_err(|source| Error::JetStreamBind {
+                    stream: stream.clone(),
+                    source,
+                })?;
+                Some(js)
+            }
+            None => None,
+        };
+
+        let mut num_published = 0u64;
+        loop {
+            ::tokio::select! {
+                biased;
+
+                () = cancel.cancelled() => {
+                    info!("Received cancellation notification; draining remaining responses");
+                    while let Ok(resp) = self.from_source.try_recv() {
+                        num_published += self.publish_and_forward(&client, jetstream.as_ref(), &to_appender, resp).await;
+                    }
+                    return Ok(num_published);
+                }
+
+                recvd = self.from_source.recv() => {
+                    let Some(resp) = recvd else {
+                        info!("Source channel closed & drained; exiting...");
+                        return Ok(num_published);
+                    };
+                    num_published += self.publish_and_forward(&client, jetstream.as_ref(), &to_appender, resp).await;
+                }
+            }
+        }
+    }
+}
+
+impl<Resp> Nats<Resp>
+where
+    Resp: Serialize + Send + Debug + 'static,
+{
+    /// Serializes and publishes `resp` to NATS and, if configured, also forwards it to
+    /// `to_appender`. Returns `1` if `resp` was actually accepted by NATS (see [`Self::publish`]),
+    /// `0` if serialization or the publish itself failed (errors are logged, not propagated, so
+    /// one bad `Response` cannot sink the whole run).
+    async fn publish_and_forward(
+        &self,
+        client: &::async_nats::Client,
+        jetstream: Option<&::async_nats::jetstream::Context>,
+        to_appender: &mpsc::Sender<Resp>,
+        resp: Resp,
+    ) -> u64 {
+        let published = match ::serde_json::to_vec(&resp) {
+            Ok(payload) => {
+                u64::from(Self::publish(&self.subject_prefix, client, jetstream, payload).await)
+            }
+            Err(err) => {
+                error!(error = ?err, ?resp, "Failed to JSON-serialize Response");
+                0
+            }
+        };
+        if self.forward_to_file {
+            if let Err(err) = to_appender.send(resp).await {
+                warn!(error = ?err, "Failed to forward Response to file-appender");
+            }
+        }
+        published
+    }
+}
@@ -1,7 +1,8 @@
 use std::{convert::Infallible, fmt::Debug, marker::PhantomData};
 
 use serde::Serialize;
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing::{info, instrument, Level};
 
 #[derive(Debug, Default)]
@@ -30,7 +31,7 @@ where
     async fn run(
         self,
         to_appender: mpsc::Sender<Self::Response>,
-        mut quit_rx: broadcast::Receiver<()>,
+        cancel: CancellationToken,
     ) -> Result<u64, Self::Error> {
         if !self.waiting {
             return Ok(0);
@@ -38,9 +39,9 @@ where
 
         // Let FileAppender die early...
         drop(to_appender);
-        // ...and wait on the quit channel before exiting.
-        let received = quit_rx.recv().await;
-        info!(?received, "Received notification from the quit channel");
+        // ...and wait for cancellation before exiting.
+        cancel.cancelled().await;
+        info!("Received cancellation notification");
         Ok(0)
     }
 }
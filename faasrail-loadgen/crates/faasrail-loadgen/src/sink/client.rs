@@ -6,12 +6,15 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use tokio::{
-    sync::{broadcast, mpsc},
-    task::JoinHandle,
-};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, instrument, warn_span, Level};
 
+use crate::metrics::MetricsRegistry;
+
+/// Capacity of the `mpsc` channel between the sink backend and the file-appender task.
+const APPENDER_CHANNEL_CAPACITY: usize = 1 << 15;
+
 #[derive(Debug, ::thiserror::Error)]
 pub enum Error {
     #[error("I/O error: {msg}")]
@@ -26,6 +29,7 @@ pub enum Error {
 pub struct SinkClient<B: super::backend::Backend> {
     csv_path: PathBuf,
     backend: B,
+    metrics: Option<MetricsRegistry>,
 }
 
 impl<B: super::backend::Backend> SinkClient<B> {
@@ -33,15 +37,24 @@ impl<B: super::backend::Backend> SinkClient<B> {
         Ok(Self {
             csv_path: path.as_ref().to_path_buf(),
             backend,
+            metrics: None,
         })
     }
 
+    /// Attaches a [`MetricsRegistry`] so the appender's `mpsc` channel depth/backpressure is
+    /// reported over `/metrics`.
+    pub fn with_metrics(mut self, metrics: MetricsRegistry) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     #[instrument(level = Level::INFO, skip_all)]
-    pub async fn run(self, quit_rx: broadcast::Receiver<()>) -> Result<u64, Error> {
-        let (to_appender, from_sink) = mpsc::channel(1 << 15); // FIXME: chan cap ?
-        let appender_handle = Self::spawn_appender(&self.csv_path, from_sink)?;
+    pub async fn run(self, cancel: CancellationToken) -> Result<u64, Error> {
+        let (to_appender, from_sink) = mpsc::channel(APPENDER_CHANNEL_CAPACITY);
+        let appender_handle =
+            Self::spawn_appender(&self.csv_path, from_sink, self.metrics.clone())?;
         let sink_backend_handle =
-            ::tokio::spawn(async move { self.backend.run(to_appender, quit_rx).await });
+            ::tokio::spawn(async move { self.backend.run(to_appender, cancel).await });
 
         let mut num_responses = 0;
         match ::tokio::try_join!(appender_handle, sink_backend_handle) {
@@ -66,6 +79,7 @@ impl<B: super::backend::Backend> SinkClient<B> {
     fn spawn_appender(
         path: impl AsRef<Path>,
         mut from_sink: mpsc::Receiver<B::Response>,
+        metrics: Option<MetricsRegistry>,
     ) -> Result<JoinHandle<Result<u64, Error>>, Error> {
         let appender_handle = ::tokio::task::spawn_blocking({
             let mut bw = BufWriter::with_capacity(
@@ -88,6 +102,9 @@ impl<B: super::backend::Backend> SinkClient<B> {
                 // Receive responses until Sink's channel has been closed & drained
                 let mut num_resps = 0;
                 while let Some(resp) = from_sink.blocking_recv() {
+                    if let Some(ref metrics) = metrics {
+                        metrics.set_appender_depth(from_sink.len(), APPENDER_CHANNEL_CAPACITY);
+                    }
                     if let Err(err) = ::serde_json::to_writer(&mut bw, &resp) {
                         error!(error = ?err, "Failed to append to file JSON-encoded '{resp:?}': {err:#}");
                     }
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use tracing::{instrument, Level};
+
+use crate::{fixer::FbpmlPayloadFixer, source::backend::Backend, InvocationId, WorkloadRequest};
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum Error {
+    #[error("failed to adjust the payload of {wreq:?}")]
+    FbpmlPayloadFix {
+        wreq: Box<WorkloadRequest>,
+        #[source]
+        source: crate::fixer::Error,
+    },
+
+    #[error("transport error invoking gateway at {url}")]
+    Transport {
+        url: Box<str>,
+        #[source]
+        source: ::reqwest::Error,
+    },
+
+    #[error("gateway at {url} returned non-success status {status}")]
+    Status { url: Box<str>, status: ::reqwest::StatusCode },
+
+    #[error("request to gateway at {url} timed out after {timeout:?}")]
+    Timeout { url: Box<str>, timeout: Duration },
+}
+
+/// Invokes each [`WorkloadRequest`] against a live FaaS gateway over HTTP, running the payload
+/// through [`FbpmlPayloadFixer::fix_payload`] first so the `minio_address`/`bucket_name` keys
+/// point at the live MinIO deployment. This is the missing piece that makes the whole replay
+/// pipeline do real work rather than just logging the generated schedule.
+///
+/// This is a library-only extension point: `faasrail-loadgen-logger`'s CLI always drives
+/// [`SourceClient`](crate::source::SourceClient) with the `Logger`'s `LoggerRef`, so exercising
+/// `HttpBackend` currently means embedding `faasrail-loadgen` directly rather than invoking the
+/// shipped binary.
+#[derive(Debug, Clone)]
+pub struct HttpBackend {
+    client: ::reqwest::Client,
+    gateway_url: Box<str>,
+    fixer: FbpmlPayloadFixer,
+}
+
+impl HttpBackend {
+    pub fn new(gateway_url: impl Into<Box<str>>, minio_address: &str, bucket_name: &str) -> Self {
+        Self {
+            client: ::reqwest::Client::new(),
+            gateway_url: gateway_url.into(),
+            fixer: FbpmlPayloadFixer::new(minio_address, bucket_name),
+        }
+    }
+}
+
+impl Backend for HttpBackend {
+    type Error = Error;
+
+    #[instrument(level = Level::INFO, skip(self, wreq))]
+    async fn issue(
+        &mut self,
+        invocation_id: InvocationId,
+        wreq: &WorkloadRequest,
+        minute: u16,
+        timeout: Duration,
+    ) -> Result<(), Self::Error> {
+        let mut wreq = wreq.clone();
+        self.fixer
+            .fix_payload(&mut wreq)
+            .map_err(|source| Error::FbpmlPayloadFix {
+                wreq: Box::new(wreq.clone()),
+                source,
+            })?;
+
+        let request = self
+            .client
+            .post(&*self.gateway_url)
+            .header("X-FaaSRail-Invocation-Id", invocation_id.as_str())
+            .header("X-FaaSRail-Minute", minute.to_string())
+            .json(&wreq);
+
+        let response = ::tokio::time::timeout(timeout, request.send())
+            .await
+            .map_err(|_elapsed| Error::Timeout {
+                url: self.gateway_url.clone(),
+                timeout,
+            })?
+            .map_err(|source| Error::Transport {
+                url: self.gateway_url.clone(),
+                source,
+            })?;
+
+        if !response.status().is_success() {
+            return Err(Error::Status {
+                url: self.gateway_url.clone(),
+                status: response.status(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+mod http;
 mod noop;
+pub use http::Error as HttpError;
+pub use http::HttpBackend;
 pub use noop::NoOp;
 
 use std::{error::Error as stdError, fmt::Debug, future::Future, time::Duration};
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt::Debug,
     fs::File,
     io::{BufRead, BufReader, BufWriter, Write},
@@ -10,25 +11,49 @@ use std::{
 use compact_str::CompactString;
 use rand_chacha::rand_core::{RngCore, SeedableRng};
 use tokio::{
-    sync::{broadcast, mpsc, Barrier},
-    task::{JoinHandle, JoinSet},
+    sync::{mpsc, Barrier},
+    task::{self, JoinHandle, JoinSet},
+    time::{sleep, Instant},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, info_span, instrument, warn, Level};
 
-use crate::source::{
-    backend::Backend,
-    iat::IatGenerator,
-    worker::{Error as WorkerError, FunctionWorker, WorkerSync},
-    Error, FunctionRow, MinuteRange,
+use crate::{
+    codec::{Codec, TraceEntry},
+    metrics::MetricsRegistry,
+    source::{
+        backend::Backend,
+        iat::IatGenerator,
+        pacing::Tranquilizer,
+        worker::{
+            self, Error as WorkerError, FunctionWorker, ReplayPacing, ReplayWorker, RetryPolicy,
+            TraceReplayWorker, WorkerProgress, WorkerSync,
+        },
+        Error, FunctionRow, MinuteRange, TraceWindow, WorkerOutcome, WorkerStatus,
+    },
+    WorkloadRequest,
 };
 
 type SecureRng = ::rand_chacha::ChaCha12Rng;
 
 #[derive(Debug)]
 pub struct SourceClient {
-    workers: JoinSet<Result<u64, WorkerError>>,
-    quit_tx: broadcast::Sender<()>,
+    workers: JoinSet<Result<WorkerStatus, WorkerError>>,
+    /// Live progress of still-running Workers, keyed by their `workers` task ID, so an accurate
+    /// [`WorkerStatus`] can still be reported for one that gets aborted past the drain deadline.
+    progress: HashMap<task::Id, Arc<WorkerProgress>>,
+    /// Parent of each Worker's [`CancellationToken`], cancelled by [`Self::run`] once a shutdown
+    /// is requested.
+    cancel: CancellationToken,
+    /// Rendezvous between [`Self::run`] and every Worker's start gate (sized one more than the
+    /// number of Workers that wait on it: replay Workers don't, so for them it's sized 1), so
+    /// Workers spawned in [`Self::new`]/[`Self::new_replay`] don't start
+    /// timing their run before the caller actually calls [`Self::run`].
+    start: Arc<Barrier>,
     inv_log_h: Option<JoinHandle<Result<(), Error>>>,
+    /// How long [`Self::run`] waits for Workers to drain cooperatively after a shutdown signal,
+    /// before forcefully aborting whichever ones remain.
+    drain_grace: Duration,
 }
 
 impl SourceClient {
@@ -43,9 +68,27 @@ impl SourceClient {
         invoc_id_start: u64,
         minute_range: MinuteRange,
         backend: B,
+        retry_policy: RetryPolicy,
         minio_address: &str,
         bucket_name: &str,
+        pacing_gain: Option<f64>,
+        tranquilizer_target_rps: Option<f64>,
+        tranquilizer_smoothing: f64,
+        rpm_controller: bool,
+        metrics: Option<MetricsRegistry>,
+        drain_grace: Duration,
     ) -> Result<Self, Error> {
+        if rpm_controller && (pacing_gain.is_some() || tranquilizer_target_rps.is_some()) {
+            return Err(Error::ConflictingRpmController {
+                msg: "rpm_controller replaces the open-loop IAT stream entirely, so it cannot be \
+                      combined with pacing_gain or tranquilizer_target_rps: both would be \
+                      silently ignored"
+                    .into(),
+            });
+        }
+
+        let tranquilizer = tranquilizer_target_rps
+            .map(|target_rps| Tranquilizer::new(target_rps, tranquilizer_smoothing));
         let mut rng = match seed {
             Some(0) => SecureRng::seed_from_u64(Self::DEFAULT_FIXED_SEED),
             Some(seed) => SecureRng::seed_from_u64(seed),
@@ -66,8 +109,10 @@ impl SourceClient {
             barrier: Barrier::new(rows.len()),
             invoc_id: AtomicU64::new(invoc_id_start),
         });
-        let (quit_tx, _) = broadcast::channel(1);
+        let cancel = CancellationToken::new();
+        let start = Arc::new(Barrier::new(rows.len() + 1));
         let mut workers = JoinSet::new();
+        let mut progress = HashMap::new();
         rows.into_iter()
             .try_for_each(|row| {
                 FunctionWorker::new(
@@ -75,17 +120,25 @@ impl SourceClient {
                     rng.next_u64(),
                     iat_gen.clone(),
                     Arc::clone(&sync),
+                    Arc::clone(&start),
                     minute_range,
                     backend.clone(),
+                    retry_policy,
                     minio_address,
                     bucket_name,
+                    pacing_gain,
+                    tranquilizer.clone(),
+                    rpm_controller,
+                    metrics.clone(),
                     inv_log.as_ref().map(|(to_inv_log, _)| InvocationLoggerRef {
                         tx: to_inv_log.clone(),
                     }),
-                    quit_tx.subscribe(),
+                    cancel.child_token(),
                 )
                 .map(|worker| {
-                    workers.spawn(async move { worker.run().await });
+                    let worker_progress = worker.progress();
+                    let abort_handle = workers.spawn(async move { worker.run().await });
+                    progress.insert(abort_handle.id(), worker_progress);
                 })
             })
             .map_err(Error::Worker)?;
@@ -96,49 +149,244 @@ impl SourceClient {
 
         Ok(Self {
             workers,
-            quit_tx,
+            progress,
+            cancel,
+            start,
             inv_log_h,
+            drain_grace,
         })
     }
 
-    #[instrument(level = Level::INFO, skip_all)]
-    pub async fn run(&mut self, mut quit_rx: broadcast::Receiver<()>) -> Result<u64, Error> {
-        let num_suscribers = self.quit_tx.send(()).expect("TODO"); // FIXME: error handling?
-        assert_eq!(
-            num_suscribers,
-            self.workers.len(),
-            "All Workers should be subscribed to quit_tx",
+    /// Builds a [`SourceClient`] that replays a previously recorded invocation log (as written
+    /// by [`InvocationLogger`]) instead of sampling IATs from scratch: `csv_path`'s `WorkloadRequest`s
+    /// are joined back onto each logged `bench`, and the exact recorded `(InvocationId, bench)`
+    /// sequence is re-issued, in order, against `backend`. This lets two runs against different
+    /// `Backend`s be compared request-for-request.
+    ///
+    /// This is a library-only extension point: `faasrail-loadgen-logger`'s CLI has no mode flag
+    /// that calls this constructor, so an A/B comparison currently means embedding
+    /// `faasrail-loadgen` directly rather than invoking the shipped binary.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_replay<B: Backend>(
+        csv_path: impl AsRef<Path>,
+        replay_log_path: impl AsRef<Path>,
+        backend: B,
+        minio_address: &str,
+        bucket_name: &str,
+        issue_timeout: Duration,
+        metrics: Option<MetricsRegistry>,
+        drain_grace: Duration,
+    ) -> Result<Self, Error> {
+        let wreqs_by_bench: HashMap<CompactString, WorkloadRequest> = Self::parse_csv(&csv_path)?
+            .into_iter()
+            .map(|row| {
+                worker::parse_wreq(&row.mapped_wreq, minio_address, bucket_name)
+                    .map(|wreq| (wreq.bench.clone(), wreq))
+                    .map_err(Error::Worker)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let entries = Self::parse_replay_log(&replay_log_path)?
+            .into_iter()
+            .map(|(invocation_id, bench)| {
+                wreqs_by_bench
+                    .get(&bench)
+                    .cloned()
+                    .map(|wreq| (invocation_id, wreq))
+                    .ok_or(Error::ReplayUnknownBench { bench })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cancel = CancellationToken::new();
+        // A `ReplayWorker` doesn't wait on the start gate (it has no per-minute schedule to time
+        // against), so `Self::run` is the gate's only party.
+        let start = Arc::new(Barrier::new(1));
+        let mut workers = JoinSet::new();
+        let mut progress = HashMap::new();
+        let worker = ReplayWorker::new(entries, backend, issue_timeout, metrics, cancel.child_token());
+        let worker_progress = worker.progress();
+        let abort_handle = workers.spawn(async move { worker.run().await });
+        progress.insert(abort_handle.id(), worker_progress);
+
+        Ok(Self {
+            workers,
+            progress,
+            cancel,
+            start,
+            inv_log_h: None,
+            drain_grace,
+        })
+    }
+
+    /// Parses an invocation log written by [`InvocationLogger`] (one `{invocation_id: bench}`
+    /// JSON object per line) back into an ordered `(InvocationId, bench)` sequence.
+    fn parse_replay_log(
+        replay_log_path: impl AsRef<Path>,
+    ) -> Result<Vec<(CompactString, CompactString)>, Error> {
+        let br = BufReader::new(File::options().read(true).open(&replay_log_path).map_err(
+            |err| Error::Io {
+                msg: format!(
+                    "failed to open invocation log file {:?}",
+                    replay_log_path.as_ref()
+                )
+                .into_boxed_str(),
+                source: err,
+            },
+        )?);
+
+        br.lines()
+            .filter(|line| !matches!(line, Ok(line) if line.is_empty()))
+            .map(|line| {
+                let line = line.map_err(|err| Error::Io {
+                    msg: "failed to read line from invocation log file".into(),
+                    source: err,
+                })?;
+                let entry: HashMap<CompactString, CompactString> =
+                    ::serde_json::from_str(&line).map_err(|err| Error::ReplayDeserialization {
+                        msg: format!("invocation log line {line:?}").into_boxed_str(),
+                        source: Some(err),
+                    })?;
+                entry
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| Error::ReplayDeserialization {
+                        msg: format!("invocation log line {line:?} has no entries").into_boxed_str(),
+                        source: None,
+                    })
+            })
+            .collect()
+    }
+
+    /// Builds a [`SourceClient`] that replays a previously captured trace log (as written by
+    /// `faasrail-loadgen-logger`'s `Logger`, in whichever format `codec` matches — the log itself
+    /// doesn't identify its own format, so the caller must pass the same [`Codec`] the log was
+    /// written with) against `backend`, honoring (or, under [`ReplayPacing::AsFastAsPossible`],
+    /// ignoring) the recorded inter-arrival gaps. Unlike [`Self::new_replay`], no input CSV is
+    /// needed: the trace log already carries each request's full `WorkloadRequest`.
+    ///
+    /// This is a library-only extension point: `faasrail-loadgen-logger`'s CLI has no mode flag
+    /// that calls this constructor, so closing the record/replay loop currently means embedding
+    /// `faasrail-loadgen` directly rather than invoking the shipped binary.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_trace_replay<B: Backend>(
+        trace_log_path: impl AsRef<Path>,
+        codec: Box<dyn Codec>,
+        window: TraceWindow,
+        backend: B,
+        pacing: ReplayPacing,
+        issue_timeout: Duration,
+        metrics: Option<MetricsRegistry>,
+        drain_grace: Duration,
+    ) -> Result<Self, Error> {
+        let entries = Self::parse_trace_log(&trace_log_path, codec)?;
+
+        let cancel = CancellationToken::new();
+        // A `TraceReplayWorker` doesn't wait on the start gate (it has no per-minute schedule to
+        // time against), so `Self::run` is the gate's only party.
+        let start = Arc::new(Barrier::new(1));
+        let mut workers = JoinSet::new();
+        let mut progress = HashMap::new();
+        let worker = TraceReplayWorker::new(
+            entries,
+            window,
+            backend,
+            pacing,
+            issue_timeout,
+            metrics,
+            cancel.child_token(),
         );
+        let worker_progress = worker.progress();
+        let abort_handle = workers.spawn(async move { worker.run().await });
+        progress.insert(abort_handle.id(), worker_progress);
+
+        Ok(Self {
+            workers,
+            progress,
+            cancel,
+            start,
+            inv_log_h: None,
+            drain_grace,
+        })
+    }
+
+    /// Parses a trace log written by `faasrail-loadgen-logger`'s `Logger` into an unordered list
+    /// of entries, decoding it with `codec` (which must match the one the log was written with);
+    /// [`TraceReplayWorker::new`] sorts and window-filters them.
+    fn parse_trace_log(
+        trace_log_path: impl AsRef<Path>,
+        mut codec: Box<dyn Codec>,
+    ) -> Result<Vec<TraceEntry>, Error> {
+        let mut br = BufReader::new(File::options().read(true).open(&trace_log_path).map_err(
+            |err| Error::Io {
+                msg: format!("failed to open trace log file {:?}", trace_log_path.as_ref())
+                    .into_boxed_str(),
+                source: err,
+            },
+        )?);
+
+        let mut entries = Vec::new();
+        while let Some(entry) = codec.decode(&mut br).map_err(|err| Error::TraceDeserialization {
+            msg: "failed to decode trace log entry".into(),
+            source: err,
+        })? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    #[instrument(level = Level::INFO, skip_all)]
+    pub async fn run(&mut self, shutdown: CancellationToken) -> Result<Vec<WorkerStatus>, Error> {
+        self.start.wait().await;
+
+        let mut statuses = Vec::with_capacity(self.workers.len());
+        // Armed once shutdown is requested: gives Workers `self.drain_grace` to finish their
+        // current `Backend::issue` call and return cooperatively, before the branch below
+        // forcefully aborts whichever ones haven't.
+        let mut draining = false;
+        let grace_deadline = sleep(self.drain_grace);
+        ::tokio::pin!(grace_deadline);
 
-        let mut num_requests = 0;
         loop {
             ::tokio::select! {
-                res = quit_rx.recv() => {
-                    warn!(received = ?res, "Received shutdown signal");
-                    match self.quit_tx.send(()) {
-                        Ok(num_suscribers) if num_suscribers == self.workers.len() => continue,
-                        // NOTE: When we receive >1 shutdown signals, some Workers might have
-                        // already dropped their Receiver, which currently leads to forceful
-                        // abortion of all the rest Worker tasks:
-                        Ok(num_suscribers) => warn!(
-                            "subscribed = {num_suscribers}; expected = {}", self.workers.len(),
-                        ),
-                        Err(err) => error!(error = ?err, "Failed to broadcast quit signal: {err:#}"),
-                    }
-                    warn!("Forcefully aborting all Worker tasks...");
+                biased;
+
+                () = shutdown.cancelled(), if !draining => {
+                    warn!("Received shutdown signal");
+                    self.cancel.cancel();
+                    info!(grace = ?self.drain_grace, "Giving Workers a grace period to drain...");
+                    draining = true;
+                    grace_deadline.as_mut().reset(Instant::now() + self.drain_grace);
+                }
+
+                () = &mut grace_deadline, if draining => {
+                    warn!("Drain grace period elapsed; forcefully aborting remaining Worker tasks...");
                     self.workers.abort_all();
+                    draining = false;
                 }
-                wrk_res = self.workers.join_next() => {
+
+                wrk_res = self.workers.join_next_with_id() => {
                     match wrk_res {
-                        Some(Ok(Ok(worker_requests))) => {
-                            num_requests += worker_requests;
-                            info!(?worker_requests, "Worker task joined successfully");
+                        Some(Ok((id, Ok(status)))) => {
+                            self.progress.remove(&id);
+                            info!(?status, "Worker task joined successfully");
+                            statuses.push(status);
                         },
-                        Some(Ok(Err(err))) => warn!(error = ?err, "Joined failed Worker task: {err:#}"),
-                        Some(Err(jerr)) if jerr.is_cancelled() => warn!("Joined aborted Worker task"),
-                        Some(Err(jerr)) => error!(error = ?jerr, "Failed to join Worker task: {jerr:#}"),
+                        Some(Ok((id, Err(err)))) => {
+                            self.progress.remove(&id);
+                            warn!(error = ?err, "Joined failed Worker task: {err:#}");
+                        }
+                        Some(Err(jerr)) => {
+                            if jerr.is_cancelled() {
+                                warn!("Joined aborted Worker task");
+                            } else {
+                                error!(error = ?jerr, "Failed to join Worker task: {jerr:#}");
+                            }
+                            if let Some(progress) = self.progress.remove(&jerr.id()) {
+                                statuses.push(progress.snapshot(WorkerOutcome::Aborted));
+                            }
+                        }
                         None => {
-                            info!(?num_requests, "No more Worker tasks to join");
+                            info!(num_workers = statuses.len(), "No more Worker tasks to join");
                             break;
                         }
                     }
@@ -154,7 +402,7 @@ impl SourceClient {
                 Err(jerr) => error!(error = ?jerr, "Failed to join InvocationLogger: {jerr:#}"),
             }
         }
-        Ok(num_requests)
+        Ok(statuses)
     }
 
     pub fn parse_csv(csv_path: impl AsRef<Path>) -> Result<Vec<FunctionRow>, Error> {
@@ -1,5 +1,7 @@
 use std::io;
 
+use compact_str::CompactString;
+
 use crate::source::worker::Error as WorkerError;
 
 #[derive(Debug, ::thiserror::Error)]
@@ -7,6 +9,9 @@ pub enum Error {
     #[error("CSV deserialization error")]
     CsvDeserialization(#[source] ::csv::Error),
 
+    #[error("conflicting rpm_controller configuration: {msg}")]
+    ConflictingRpmController { msg: Box<str> },
+
     #[error("I/O error: {msg}")]
     Io {
         msg: Box<str>,
@@ -24,6 +29,30 @@ pub enum Error {
         source: Option<::std::num::ParseIntError>,
     },
 
+    #[error("invalid trace window: {msg}")]
+    TraceWindow {
+        msg: Box<str>,
+        #[source]
+        source: Option<::std::num::ParseIntError>,
+    },
+
     #[error("error in Worker")]
     Worker(#[source] WorkerError),
+
+    #[error("malformed invocation log entry: {msg}")]
+    ReplayDeserialization {
+        msg: Box<str>,
+        #[source]
+        source: Option<::serde_json::Error>,
+    },
+
+    #[error("invocation log references bench {bench:?}, which is not present in the input CSV")]
+    ReplayUnknownBench { bench: CompactString },
+
+    #[error("malformed trace log entry: {msg}")]
+    TraceDeserialization {
+        msg: Box<str>,
+        #[source]
+        source: crate::codec::Error,
+    },
 }
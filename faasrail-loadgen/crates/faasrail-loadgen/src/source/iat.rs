@@ -86,6 +86,319 @@ impl IatGenerator for Equidistant {
     }
 }
 
+/// A single state of a [`Mmpp`]: an arrival-rate multiplier and the state's row of outgoing
+/// transition rates (one entry per state, including itself, which is ignored).
+#[derive(Debug, Clone)]
+pub struct MmppState {
+    /// Multiplier applied to the per-minute `rpm` passed to [`IatGenerator::gen`] to derive this
+    /// state's λ. May be `0.0`, in which case no arrivals are emitted while in this state.
+    pub lambda_mult: f64,
+    /// Outgoing transition rates (per minute) to every other state; `out_rates[i]` is this
+    /// state's own entry and is ignored.
+    pub out_rates: Vec<f64>,
+}
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum MmppError {
+    #[error("MMPP must be configured with at least one state")]
+    NoStates,
+
+    #[error("state {state} has {given} outgoing rates, expected one per state ({expected})")]
+    MalformedRow {
+        state: usize,
+        given: usize,
+        expected: usize,
+    },
+
+    #[error("initial state {initial_state} is out of bounds (only {num_states} states)")]
+    InitialStateOutOfBounds {
+        initial_state: usize,
+        num_states: usize,
+    },
+}
+
+/// Markov-Modulated Poisson Process IATs.
+///
+/// Instead of a single stationary rate, arrivals are generated by a continuous-time Markov chain
+/// of `N` states, each with its own λ (a multiplier of `rpm`). The chain sojourns in a state for
+/// an exponential duration (rate = the sum of that state's outgoing rates), emitting exponential
+/// IATs at the state's λ while it does, then hops to a next state with probability proportional
+/// to the outgoing rates. This reproduces the heavy-tailed, bursty arrival patterns seen in real
+/// FaaS traces, which a single-rate [`Poisson`] flattens out.
+#[derive(Debug, Clone)]
+pub struct Mmpp {
+    states: Vec<MmppState>,
+    initial_state: usize,
+}
+
+impl Mmpp {
+    pub fn new(states: Vec<MmppState>, initial_state: usize) -> Result<Self, MmppError> {
+        if states.is_empty() {
+            return Err(MmppError::NoStates);
+        }
+        for (state, row) in states.iter().enumerate() {
+            if row.out_rates.len() != states.len() {
+                return Err(MmppError::MalformedRow {
+                    state,
+                    given: row.out_rates.len(),
+                    expected: states.len(),
+                });
+            }
+        }
+        if initial_state >= states.len() {
+            return Err(MmppError::InitialStateOutOfBounds {
+                initial_state,
+                num_states: states.len(),
+            });
+        }
+        Ok(Self {
+            states,
+            initial_state,
+        })
+    }
+
+    /// Sojourn duration (μs) for `state`: exponential with rate = Σ outgoing rates. A state with
+    /// no outgoing rates (an absorbing state) sojourns for the rest of the minute.
+    fn sample_sojourn<R: Rng>(state: &MmppState, rng: &mut R) -> f64 {
+        let total_out: f64 = state.out_rates.iter().sum();
+        if total_out <= 0. {
+            return MICROSECONDS_PER_MINUTE;
+        }
+        ::rand_distr::Exp::new(total_out / MICROSECONDS_PER_MINUTE)
+            .expect("Σ out_rates > 0")
+            .sample(rng)
+    }
+
+    /// Picks the next state with probability proportional to `states[current].out_rates`.
+    fn next_state<R: Rng>(states: &[MmppState], current: usize, rng: &mut R) -> usize {
+        let total_out: f64 = states[current].out_rates.iter().sum();
+        if total_out <= 0. {
+            return current;
+        }
+        let pick = rng.gen::<f64>() * total_out;
+        let mut acc = 0.;
+        for (next, &rate) in states[current].out_rates.iter().enumerate() {
+            acc += rate;
+            if pick < acc {
+                return next;
+            }
+        }
+        // Rounding error landed us just past the last bucket; stay put rather than panic.
+        current
+    }
+}
+
+impl IatGenerator for Mmpp {
+    type Error = MmppError;
+
+    fn gen<R: Rng + Send + Sync + 'static>(
+        &self,
+        rpm: u32,
+        mut rng: R,
+    ) -> Result<impl FusedIterator<Item = MicroSeconds> + Send + Sync + 'static, Self::Error> {
+        let states = self.states.clone();
+        let mut current = self.initial_state;
+        let mut sojourn = Self::sample_sojourn(&states[current], &mut rng);
+        let mut elapsed_in_state = 0.;
+        let mut iats_sum = 0.;
+
+        Ok(::std::iter::from_fn(move || loop {
+            if elapsed_in_state >= sojourn {
+                current = Self::next_state(&states, current, &mut rng);
+                sojourn = Self::sample_sojourn(&states[current], &mut rng);
+                elapsed_in_state = 0.;
+            }
+
+            let lambda = states[current].lambda_mult * rpm as f64 / MICROSECONDS_PER_MINUTE;
+            let iat = if lambda > 0. {
+                ::rand_distr::Exp::new(lambda)
+                    .expect("λ > 0")
+                    .sample(&mut rng)
+            } else {
+                // λ=0: nothing to emit, but we must still let the sojourn timer expire so the
+                // chain can leave this (otherwise silent) state.
+                sojourn - elapsed_in_state
+            };
+
+            elapsed_in_state += iat;
+            iats_sum += iat;
+            if iats_sum >= MICROSECONDS_PER_MINUTE {
+                return None;
+            }
+            if lambda > 0. {
+                return Some(iat as u64);
+            }
+        })
+        .fuse())
+    }
+}
+
+#[derive(Debug, ::thiserror::Error)]
+pub enum MarkovChainError {
+    #[error("`num_buckets` must be at least 1")]
+    NoBuckets,
+
+    #[error("cannot fit a Markov chain from fewer than 2 observed inter-arrival times")]
+    TooFewSamples,
+}
+
+/// Log-spaced discretization of an empirical inter-arrival-time range into `num_buckets`
+/// contiguous, half-open buckets covering `[min_iat, max_iat]`.
+#[derive(Debug, Clone)]
+struct Buckets {
+    /// `num_buckets + 1` bucket edges (μs), log-spaced, monotonically increasing.
+    edges: Vec<f64>,
+}
+
+impl Buckets {
+    fn fit(iats_us: &[MicroSeconds], num_buckets: usize) -> Self {
+        let min = iats_us.iter().copied().filter(|&iat| iat > 0).min().unwrap_or(1) as f64;
+        let max = (iats_us.iter().copied().max().unwrap_or(1) as f64).max(min + 1.);
+        let (log_min, log_max) = (min.ln(), max.ln());
+        let step = (log_max - log_min) / num_buckets as f64;
+        Self {
+            edges: (0..=num_buckets)
+                .map(|i| (log_min + step * i as f64).exp())
+                .collect(),
+        }
+    }
+
+    fn num_buckets(&self) -> usize {
+        self.edges.len() - 1
+    }
+
+    /// Index of the bucket containing `iat_us`, clamped to the configured range.
+    fn bucket_of(&self, iat_us: MicroSeconds) -> usize {
+        let x = (iat_us as f64).clamp(self.edges[0], *self.edges.last().expect("non-empty"));
+        match self.edges.binary_search_by(|edge| edge.partial_cmp(&x).expect("no NaNs")) {
+            Ok(i) => i.min(self.num_buckets() - 1),
+            Err(i) => i.saturating_sub(1).min(self.num_buckets() - 1),
+        }
+    }
+
+    /// Samples a concrete μs duration log-uniformly within bucket `i`'s bounds.
+    fn sample<R: Rng>(&self, i: usize, rng: &mut R) -> f64 {
+        let (log_lo, log_hi) = (self.edges[i].max(1.).ln(), self.edges[i + 1].max(2.).ln());
+        (log_lo + rng.gen::<f64>() * (log_hi - log_lo)).exp()
+    }
+}
+
+/// A first-order Markov chain over log-spaced inter-arrival-time buckets, fit from an empirical
+/// trace of observed IATs.
+///
+/// Unlike [`Poisson`] or [`Uniform`], which draw i.i.d. samples and so flatten out any
+/// autocorrelation in the arrival process, `MarkovChain` preserves the trace's burstiness: each
+/// emitted IAT is drawn from the bucket the chain transitions *into*, conditioned on the bucket it
+/// is currently in, so runs of short (or long) IATs in the training trace reproduce as runs in the
+/// generated stream. A row that was never observed to transition out (an absorbing or unseen
+/// state) falls back to the trace's marginal (stationary) distribution, so sampling never stalls.
+#[derive(Debug, Clone)]
+pub struct MarkovChain {
+    buckets: Buckets,
+    /// Per-state row CDF; `row_cdfs[i][j]` is the cumulative probability of transitioning from
+    /// state `i` to a state `<= j`. Empty when state `i` was never observed to transition out.
+    row_cdfs: Vec<Vec<f32>>,
+    /// Marginal (stationary) distribution CDF over buckets: the fallback for unseen/absorbing
+    /// states, and the distribution the initial state is drawn from.
+    marginal_cdf: Vec<f32>,
+    /// RPM the training trace was observed at; sampled durations are scaled by
+    /// `reference_rpm / rpm` so the generated stream's shape matches the empirical one while its
+    /// mean rate tracks whatever `rpm` [`IatGenerator::gen`] is asked for.
+    reference_rpm: u32,
+}
+
+impl MarkovChain {
+    /// Fits a chain from `observed_iats_us`, a trace of consecutive inter-arrival times (μs)
+    /// observed at `reference_rpm`, discretized into `num_buckets` log-spaced buckets.
+    pub fn fit(
+        reference_rpm: u32,
+        observed_iats_us: &[MicroSeconds],
+        num_buckets: usize,
+    ) -> Result<Self, MarkovChainError> {
+        if num_buckets == 0 {
+            return Err(MarkovChainError::NoBuckets);
+        }
+        if observed_iats_us.len() < 2 {
+            return Err(MarkovChainError::TooFewSamples);
+        }
+
+        let buckets = Buckets::fit(observed_iats_us, num_buckets);
+        let states = observed_iats_us
+            .iter()
+            .map(|&iat| buckets.bucket_of(iat))
+            .collect::<Vec<_>>();
+
+        let mut counts = vec![vec![0u64; num_buckets]; num_buckets];
+        for pair in states.windows(2) {
+            counts[pair[0]][pair[1]] += 1;
+        }
+        let mut marginal_counts = vec![0u64; num_buckets];
+        for &state in &states {
+            marginal_counts[state] += 1;
+        }
+
+        Ok(Self {
+            buckets,
+            row_cdfs: counts.iter().map(|row| Self::normalize_to_cdf(row)).collect(),
+            marginal_cdf: Self::normalize_to_cdf(&marginal_counts),
+            reference_rpm,
+        })
+    }
+
+    /// Row-normalizes `counts` into a cumulative distribution; returns an empty `Vec` if every
+    /// count is zero (the caller falls back to `marginal_cdf` in that case).
+    fn normalize_to_cdf(counts: &[u64]) -> Vec<f32> {
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        let mut acc = 0u64;
+        counts
+            .iter()
+            .map(|&count| {
+                acc += count;
+                acc as f32 / total as f32
+            })
+            .collect()
+    }
+
+    /// Inverts `cdf` via a uniform draw from `rng`, falling back to `marginal_cdf` if `cdf` is
+    /// empty (an unseen/absorbing state).
+    fn next_state<R: Rng>(&self, cdf: &[f32], rng: &mut R) -> usize {
+        let cdf = if cdf.is_empty() { &self.marginal_cdf } else { cdf };
+        let pick: f32 = rng.gen();
+        cdf.iter().position(|&cum| pick < cum).unwrap_or(cdf.len() - 1)
+    }
+}
+
+impl IatGenerator for MarkovChain {
+    type Error = Infallible;
+
+    fn gen<R: Rng + Send + Sync + 'static>(
+        &self,
+        rpm: u32,
+        mut rng: R,
+    ) -> Result<impl FusedIterator<Item = MicroSeconds> + Send + Sync + 'static, Self::Error> {
+        let this = self.clone();
+        let mut current = this.next_state(&this.marginal_cdf, &mut rng);
+        let scale = this.reference_rpm as f64 / (rpm.max(1) as f64);
+        let mut iats_sum = 0.;
+
+        Ok(::std::iter::from_fn(move || {
+            let next = this.next_state(&this.row_cdfs[current], &mut rng);
+            let iat = this.buckets.sample(next, &mut rng) * scale;
+            current = next;
+
+            iats_sum += iat;
+            if iats_sum >= MICROSECONDS_PER_MINUTE {
+                return None;
+            }
+            Some(iat as u64)
+        })
+        .fuse())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Instant;
@@ -95,7 +408,10 @@ mod tests {
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use super::{Equidistant, IatGenerator, Poisson, Uniform, MICROSECONDS_PER_SECOND};
+    use super::{
+        Equidistant, IatGenerator, MarkovChain, Mmpp, MmppState, Poisson, Uniform,
+        MICROSECONDS_PER_SECOND,
+    };
 
     #[test]
     #[traced_test]
@@ -189,4 +505,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[traced_test]
+    fn mmpp0() -> Result<()> {
+        //let mut rng = SmallRng::seed_from_u64(crate::source::client::DEFAULT_FIXED_SEED);
+        let rng = SmallRng::from_entropy();
+
+        // Two states: a quiet background rate and a bursty spike, with a degenerate λ=0 state
+        // thrown in to exercise the "must still leave the state" guard.
+        let m = Mmpp::new(
+            vec![
+                MmppState {
+                    lambda_mult: 0.5,
+                    out_rates: vec![0., 2., 0.],
+                },
+                MmppState {
+                    lambda_mult: 5.,
+                    out_rates: vec![3., 0., 0.],
+                },
+                MmppState {
+                    lambda_mult: 0.,
+                    out_rates: vec![4., 0., 0.],
+                },
+            ],
+            0,
+        )
+        .context("failed to build Mmpp")?;
+
+        for rpm in &[3, 25, 50, 100, 200] {
+            debug!("RPM = {rpm}");
+            let t_start = Instant::now();
+            let iats_iter = m
+                .gen(*rpm, rng.clone())
+                .context("failed to generate IATs")?;
+            let dur = t_start.elapsed();
+            let iats = iats_iter.collect::<Vec<_>>();
+            debug!("\t- Generated {} IATs in {dur:?}", iats.len());
+            debug!("\t- Raw (μs) IATs: {iats:?}");
+            debug!(
+                "\t- IATs: {:?} (Σ = {:.3}s)",
+                iats.iter()
+                    .map(|&iat| format!("{:.6}s", iat as f64 / MICROSECONDS_PER_SECOND))
+                    .collect::<Vec<_>>(),
+                iats.iter()
+                    .map(|&iat| iat as f64 / MICROSECONDS_PER_SECOND)
+                    .sum::<f64>(),
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[traced_test]
+    fn markov_chain0() -> Result<()> {
+        let rng = SmallRng::from_entropy();
+
+        // A synthetic trace alternating between a quiet run of long IATs and a bursty run of
+        // short ones, so the fitted chain has non-trivial (and distinct) row CDFs to exercise.
+        let observed_iats_us = (0..200)
+            .flat_map(|i| {
+                if i % 2 == 0 {
+                    vec![500_000, 450_000, 480_000]
+                } else {
+                    vec![1_000, 1_200, 900, 1_100]
+                }
+            })
+            .collect::<Vec<_>>();
+        let m = MarkovChain::fit(50, &observed_iats_us, 16).context("failed to fit MarkovChain")?;
+
+        for rpm in &[3, 25, 50, 100, 200] {
+            debug!("RPM = {rpm}");
+            let t_start = Instant::now();
+            let iats_iter = m
+                .gen(*rpm, rng.clone())
+                .context("failed to generate IATs")?;
+            let dur = t_start.elapsed();
+            let iats = iats_iter.collect::<Vec<_>>();
+            debug!("\t- Generated {} IATs in {dur:?}", iats.len());
+            debug!("\t- Raw (μs) IATs: {iats:?}");
+            debug!(
+                "\t- IATs: {:?} (Σ = {:.3}s)",
+                iats.iter()
+                    .map(|&iat| format!("{:.6}s", iat as f64 / MICROSECONDS_PER_SECOND))
+                    .collect::<Vec<_>>(),
+                iats.iter()
+                    .map(|&iat| iat as f64 / MICROSECONDS_PER_SECOND)
+                    .sum::<f64>(),
+            );
+        }
+
+        Ok(())
+    }
 }
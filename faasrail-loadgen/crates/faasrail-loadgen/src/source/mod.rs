@@ -3,6 +3,9 @@ mod client;
 mod error;
 mod iat;
 mod minuterange;
+mod pacing;
+mod rpm_controller;
+mod tracewindow;
 mod worker;
 
 pub use backend::Backend as SourceBackend;
@@ -10,9 +13,19 @@ pub use client::SourceClient;
 pub use error::Error;
 pub use iat::Equidistant;
 pub use iat::IatGenerator;
+pub use iat::MarkovChain;
+pub use iat::MarkovChainError;
+pub use iat::Mmpp;
+pub use iat::MmppError;
+pub use iat::MmppState;
 pub use iat::Poisson;
 pub use iat::Uniform;
 pub use minuterange::MinuteRange;
+pub use tracewindow::TraceWindow;
+pub use worker::ReplayPacing;
+pub use worker::RetryPolicy;
+pub use worker::WorkerOutcome;
+pub use worker::WorkerStatus;
 
 #[derive(Debug, ::serde::Deserialize)]
 pub struct FunctionRow {
@@ -0,0 +1,120 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Closed-loop pacing correction for [`FunctionWorker`](super::worker::FunctionWorker)'s
+/// otherwise open-loop IAT stream.
+///
+/// IATs are generated up front by an [`IatGenerator`](super::iat::IatGenerator) and then slept
+/// through open-loop; any scheduler/overhead jitter (see the `Equidistant`/`Poisson` FIXMEs about
+/// racing `minute_end`) accumulates and silently shifts the emitted schedule later and later.
+/// `PacingController` tracks the gap between the intended cumulative arrival time and the actual
+/// wall-clock emission time, and nudges each upcoming sleep by a proportional correction so the
+/// realized arrival process stays locked to the generated one over the minute.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PacingController {
+    gain: f64,
+    minute_start: Instant,
+    intended_elapsed: Duration,
+}
+
+impl PacingController {
+    /// `gain` controls how aggressively drift is corrected: `0.0` disables correction (pure
+    /// open-loop), `1.0` fully cancels the drift observed so far on the very next sleep. Values
+    /// outside `[0, 1]` are clamped.
+    pub(crate) fn new(gain: f64, minute_start: Instant) -> Self {
+        Self {
+            gain: gain.clamp(0., 1.),
+            minute_start,
+            intended_elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Folds `iat` into the intended schedule and returns the (possibly shortened or lengthened)
+    /// sleep to actually perform, correcting for the drift accumulated so far this minute. The
+    /// correction is clamped so the returned sleep never goes negative; it saturates to zero.
+    pub(crate) fn correct(&mut self, iat: Duration) -> Duration {
+        let intended_before = self.intended_elapsed;
+        self.intended_elapsed += iat;
+
+        // Positive drift: wall-clock emission is already running behind the intended schedule,
+        // so shrink this sleep to help catch up. Negative drift: we're ahead, so lengthen it.
+        let drift = self.minute_start.elapsed().as_secs_f64() - intended_before.as_secs_f64();
+        let corrected_secs = iat.as_secs_f64() - self.gain * drift;
+        Duration::try_from_secs_f64(corrected_secs.max(0.)).unwrap_or(Duration::ZERO)
+    }
+}
+
+#[derive(Debug)]
+struct TranquilizerInner {
+    target_rps: f64,
+    smoothing: f64,
+    completed: AtomicU64,
+    window: Mutex<(Instant, u64, f64)>,
+}
+
+/// A closed-loop, global throughput tranquilizer sitting between the [`IatGenerator`]-produced
+/// IATs and a worker's issue loop, holding an aggregate target requests-per-second across *all*
+/// [`FunctionWorker`](super::worker::FunctionWorker)s despite backend slowdowns.
+///
+/// Over a sliding window it tracks an EWMA of achieved throughput (completed invocations per
+/// elapsed wall time, seeded with the target RPS) and computes the error against `target_rps`:
+/// when running behind, the extra inter-arrival delay it hands back shrinks toward zero; when
+/// running ahead, it grows — clamped to at most one nominal IAT per step to avoid oscillation.
+///
+/// [`IatGenerator`]: super::iat::IatGenerator
+#[derive(Debug, Clone)]
+pub(crate) struct Tranquilizer(Arc<TranquilizerInner>);
+
+impl Tranquilizer {
+    /// `smoothing` is the EWMA's α in `[0, 1]`: `0.0` never updates the estimate away from
+    /// `target_rps`, `1.0` tracks the instantaneous rate with no smoothing at all.
+    pub(crate) fn new(target_rps: f64, smoothing: f64) -> Self {
+        let now = Instant::now();
+        Self(Arc::new(TranquilizerInner {
+            target_rps,
+            smoothing: smoothing.clamp(0., 1.),
+            completed: AtomicU64::new(0),
+            window: Mutex::new((now, 0, target_rps)),
+        }))
+    }
+
+    /// Call once per completed invocation (success or failure) to feed the achieved-throughput
+    /// estimate.
+    pub(crate) fn record_completion(&self) {
+        self.0.completed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the extra delay to add on top of `nominal_iat` so that achieved throughput
+    /// converges on the target RPS.
+    pub(crate) fn extra_delay(&self, nominal_iat: Duration) -> Duration {
+        let now = Instant::now();
+        let completed = self.0.completed.load(Ordering::Relaxed);
+
+        let achieved_rps = {
+            let mut window = self.0.window.lock().expect("poisoned");
+            let (window_start, window_completed, ewma) = *window;
+            let dt = now.duration_since(window_start).as_secs_f64();
+            if dt > 0. {
+                let instantaneous = (completed.saturating_sub(window_completed)) as f64 / dt;
+                let updated_ewma = self.0.smoothing * instantaneous + (1. - self.0.smoothing) * ewma;
+                *window = (now, completed, updated_ewma);
+                updated_ewma
+            } else {
+                ewma
+            }
+        };
+
+        // Positive error: we're running ahead of target, so add sleep. Negative: we're behind,
+        // so the extra delay saturates to zero (the nominal IAT alone is left to shrink via
+        // `PacingController`, if also enabled).
+        let error = achieved_rps - self.0.target_rps;
+        let correction_secs = (error / self.0.target_rps.max(f64::EPSILON)) * nominal_iat.as_secs_f64();
+        let clamped_secs = correction_secs.clamp(0., nominal_iat.as_secs_f64());
+        Duration::try_from_secs_f64(clamped_secs).unwrap_or(Duration::ZERO)
+    }
+}
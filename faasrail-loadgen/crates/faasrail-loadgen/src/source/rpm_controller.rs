@@ -0,0 +1,41 @@
+use std::time::{Duration, Instant};
+
+/// Opt-in, deadline-driven alternative to the open-loop IAT stream that
+/// [`FunctionWorker::run`](super::worker::FunctionWorker::run) otherwise sleeps through.
+///
+/// An [`IatGenerator`](super::iat::IatGenerator) pre-generates a minute's worth of IATs up
+/// front; scheduling overhead then silently shifts emission later and later, and whichever IATs
+/// haven't been slept through by the time `minute_end` fires are simply dropped, so the Worker
+/// can fall short of its target `rpm`. `RpmController` instead tracks, on every iteration, how
+/// many invocations *should* have been issued by now given `rpm`, and either fires immediately
+/// (if behind schedule) or sleeps until the next slot (if on schedule or ahead), converging on
+/// `rpm` despite overhead. Inspired by Garage's "tranquilizer".
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RpmController {
+    rate_per_sec: f64,
+}
+
+impl RpmController {
+    pub(crate) fn new(rpm: u32) -> Self {
+        Self {
+            rate_per_sec: f64::from(rpm) / 60.0,
+        }
+    }
+
+    /// How long to sleep before firing the `(issued + 1)`-th invocation of the minute that
+    /// started at `minute_start`. Returns [`Duration::ZERO`] when we're already behind schedule.
+    pub(crate) fn next_sleep(&self, issued: u64, minute_start: Instant) -> Duration {
+        if self.rate_per_sec <= 0.0 {
+            return Duration::from_secs(60);
+        }
+
+        let elapsed_secs = minute_start.elapsed().as_secs_f64();
+        let ideal_issued = self.rate_per_sec * elapsed_secs;
+        if (issued as f64) < ideal_issued {
+            return Duration::ZERO;
+        }
+
+        let target_secs = (issued + 1) as f64 / self.rate_per_sec;
+        Duration::from_secs_f64((target_secs - elapsed_secs).max(0.0))
+    }
+}
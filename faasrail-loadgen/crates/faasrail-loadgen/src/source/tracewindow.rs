@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use super::Error;
+
+/// An inclusive `[start, end]` range of `epoch_us` timestamps, analogous to
+/// [`MinuteRange`](super::MinuteRange) but over raw trace timestamps rather than 1-based minute
+/// indices.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct TraceWindow(u64, u64);
+
+impl Default for TraceWindow {
+    fn default() -> Self {
+        Self(0, u64::MAX)
+    }
+}
+
+impl TraceWindow {
+    pub fn new_inclusive(first: u64, last: u64) -> Result<Self, Error> {
+        if first > last {
+            return Err(Error::TraceWindow {
+                msg: format!("{first} == first > last == {last}").into_boxed_str(),
+                source: None,
+            });
+        }
+        Ok(Self(first, last))
+    }
+
+    #[inline(always)]
+    pub fn start(&self) -> u64 {
+        self.0
+    }
+
+    #[inline(always)]
+    pub fn end(&self) -> u64 {
+        self.1
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, epoch_us: u64) -> bool {
+        epoch_us >= self.0 && epoch_us <= self.1
+    }
+}
+
+impl FromStr for TraceWindow {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        s.split_once(':')
+            .or_else(|| s.split_once(".."))
+            .map_or_else(
+                || {
+                    Err(Error::TraceWindow {
+                        msg: format!("invalid format {s:?}").into_boxed_str(),
+                        source: None,
+                    })
+                },
+                |(first, last)| {
+                    Self::new_inclusive(
+                        first.trim().parse().map_err(|err| Error::TraceWindow {
+                            msg: "first".into(),
+                            source: Some(err),
+                        })?,
+                        last.trim().parse().map_err(|err| Error::TraceWindow {
+                            msg: "last".into(),
+                            source: Some(err),
+                        })?,
+                    )
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceWindow;
+
+    #[test]
+    fn tracewindow01() {
+        let default = TraceWindow::default();
+        assert_eq!(default, "0..18446744073709551615".parse().unwrap());
+        assert_eq!(default, "0:18446744073709551615".parse().unwrap());
+    }
+}
@@ -1,24 +1,30 @@
 use std::{
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicU32, AtomicU64, Ordering},
         Arc,
     },
     time::Duration,
 };
 
-use compact_str::format_compact;
+use compact_str::{format_compact, CompactString};
+use rand::Rng;
 use rand_xoshiro::rand_core::SeedableRng;
 use tokio::{
-    sync::{broadcast, Barrier},
-    time::{sleep, Instant},
+    sync::Barrier,
+    time::{sleep, timeout, Instant},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, trace, warn, Level};
 
 use crate::{
+    codec::TraceEntry,
+    metrics::MetricsRegistry,
     source::{
-        backend::Backend, client::InvocationLoggerRef, iat::IatGenerator, FunctionRow, MinuteRange,
+        backend::Backend, client::InvocationLoggerRef, iat::IatGenerator,
+        pacing::{PacingController, Tranquilizer}, rpm_controller::RpmController, FunctionRow,
+        MinuteRange, TraceWindow,
     },
-    WorkloadRequest,
+    InvocationId, WorkloadRequest,
 };
 
 #[derive(Debug, ::thiserror::Error)]
@@ -47,26 +53,174 @@ pub enum Error {
 /// Type alias to easily swap algorithms (e.g., to change it to [`rand_xoshiro::Xoshiro256Plus`]).
 type FastRng = ::rand_xoshiro::Xoshiro256PlusPlus;
 
+/// Deserializes a `FunctionRow::mapped_wreq` column and runs it through
+/// [`fix_fbpml_payload`](crate::fixer::fix_fbpml_payload), the two steps shared by
+/// [`FunctionWorker::new`] and
+/// [`SourceClient::new_replay`](super::client::SourceClient::new_replay).
+pub(crate) fn parse_wreq(
+    mapped_wreq: &str,
+    minio_address: &str,
+    bucket_name: &str,
+) -> Result<WorkloadRequest, Error> {
+    let mut wreq =
+        ::serde_json::from_str::<WorkloadRequest>(mapped_wreq).map_err(|err| {
+            Error::Deserialization {
+                msg: format!("mapped WorkloadRequest: {mapped_wreq:?}").into_boxed_str(),
+                source: err,
+            }
+        })?;
+    crate::fixer::fix_fbpml_payload(&mut wreq, minio_address, bucket_name).map_err(|err| {
+        Error::FbpmlPayloadFix {
+            wreq: Box::new(wreq.clone()),
+            source: err,
+        }
+    })?;
+    Ok(wreq)
+}
+
 #[derive(Debug)]
 pub(crate) struct WorkerSync {
     pub(crate) barrier: Barrier,
     pub(crate) invoc_id: AtomicU64,
 }
 
+/// How a [`FunctionWorker`] task ended, as surfaced in its [`WorkerStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerOutcome {
+    /// Ran to completion, or drained cleanly within the shutdown grace period.
+    Completed,
+    /// Exceeded the shutdown grace period and was forcefully aborted (e.g., mid-invocation, or
+    /// while waiting at the per-minute barrier for Workers that already stopped).
+    Aborted,
+}
+
+/// Per-[`FunctionWorker`] status report, surfaced from
+/// [`SourceClient::run`](super::client::SourceClient::run) so callers can see exactly which
+/// functions were cut off (and where) by a shutdown.
+#[derive(Debug, Clone)]
+pub struct WorkerStatus {
+    pub bench: CompactString,
+    pub last_minute: u16,
+    pub invocations_issued: u64,
+    /// Invocations [`FunctionWorker::issue_with_retry`] gave up on after exhausting
+    /// [`RetryPolicy::max_attempts`] (or running out of the minute's retry budget), as opposed to
+    /// ones never attempted because of an intentional minute/shutdown cutoff. Lets callers tell
+    /// backend-induced loss apart from deliberate truncation.
+    pub retries_exhausted: u64,
+    pub outcome: WorkerOutcome,
+}
+
+/// Live, shared snapshot of a [`FunctionWorker`]'s progress. Updated as the worker runs so that
+/// [`SourceClient::run`](super::client::SourceClient::run) can still report an accurate
+/// [`WorkerStatus`] for workers that had to be aborted after exceeding the drain grace period
+/// (whose own `run` future never gets to return one).
+#[derive(Debug)]
+pub(crate) struct WorkerProgress {
+    bench: CompactString,
+    last_minute: AtomicU32,
+    invocations_issued: AtomicU64,
+    retries_exhausted: AtomicU64,
+}
+
+impl WorkerProgress {
+    fn new(bench: CompactString) -> Self {
+        Self {
+            bench,
+            last_minute: AtomicU32::new(0),
+            invocations_issued: AtomicU64::new(0),
+            retries_exhausted: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn snapshot(&self, outcome: WorkerOutcome) -> WorkerStatus {
+        WorkerStatus {
+            bench: self.bench.clone(),
+            last_minute: self.last_minute.load(Ordering::Relaxed) as u16,
+            invocations_issued: self.invocations_issued.load(Ordering::Relaxed),
+            retries_exhausted: self.retries_exhausted.load(Ordering::Relaxed),
+            outcome,
+        }
+    }
+}
+
+/// Configures [`FunctionWorker::issue_with_retry`]'s behavior around a single `Backend::issue`
+/// call: how many attempts to make, how long one attempt may run before it's abandoned, and how
+/// the delay between attempts grows.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts per invocation, including the first. `1` disables retries outright.
+    pub max_attempts: u32,
+    /// Hard deadline for a single attempt, enforced via [`tokio::time::timeout`] (further
+    /// clamped to whatever's left of the current minute).
+    pub attempt_timeout: Duration,
+    /// Backoff window after the `n`-th failed attempt is `base_delay * 2^(n - 1)`, capped at
+    /// `max_delay`; the actual sleep is drawn uniformly from `[0, window]` ("full jitter").
+    pub base_delay: Duration,
+    /// Upper bound on the backoff window, regardless of how many attempts have elapsed.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// The full-jitter backoff window before the retry following `failed_attempts` failures so
+    /// far (1-based: `failed_attempts == 1` is the window ahead of the second attempt).
+    fn backoff(&self, failed_attempts: u32, rng: &mut FastRng) -> Duration {
+        let factor = 1u32.checked_shl(failed_attempts.saturating_sub(1)).unwrap_or(u32::MAX);
+        let window_ms = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+            .as_millis()
+            .min(u64::MAX as u128) as u64;
+        Duration::from_millis(if window_ms == 0 { 0 } else { rng.gen_range(0..=window_ms) })
+    }
+}
+
+/// Why [`FunctionWorker::issue_with_retry`] gave up on an invocation.
+#[derive(Debug)]
+enum RetryError<E> {
+    /// The last attempt ran to completion and the [`Backend`] itself reported this error.
+    Backend(E),
+    /// The last attempt timed out, or the minute's retry budget ran out before another attempt
+    /// (or its backoff) could fit; a [`Backend`] error from an earlier attempt is not reported.
+    Exhausted,
+    /// A shutdown was requested while this invocation was being retried. Not backend-induced
+    /// loss, so callers shouldn't count it alongside [`Self::Backend`]/[`Self::Exhausted`].
+    Cancelled,
+}
+
 #[derive(Debug)]
 pub(crate) struct FunctionWorker<G: IatGenerator, B: Backend> {
     sync: Arc<WorkerSync>,
+    /// Released once [`SourceClient::run`](super::client::SourceClient::run) is actually called,
+    /// so Workers spawned during [`SourceClient::new`](super::client::SourceClient::new) don't
+    /// start sleeping through their IAT stream before the caller is ready to time the run.
+    start: Arc<Barrier>,
     minute_range: MinuteRange,
 
     _pavg: f64,
     rpm: Vec<u32>,
     wreq: WorkloadRequest,
     backend: B,
+    retry_policy: RetryPolicy,
     rng: FastRng,
     iat_gen: G,
+    /// Ignored whenever `rpm_controller` is set: [`RpmController`] replaces the IAT stream these
+    /// correct, rather than layering on top of it.
+    pacing_gain: Option<f64>,
+    /// Ignored whenever `rpm_controller` is set; see its doc comment.
+    tranquilizer: Option<Tranquilizer>,
+    /// When set, [`Self::run`] fires each invocation on an [`RpmController`] deadline instead of
+    /// sleeping through the [`IatGenerator`]-produced IAT stream, bypassing `pacing_gain` and
+    /// `tranquilizer` entirely (there's no open-loop sleep left for either to correct). Callers
+    /// should treat the three as mutually exclusive; `faasrail-loadgen-logger`'s CLI rejects
+    /// `--rpm-controller` alongside `--pacing-gain`/`--tranquilizer-target-rps` for this reason.
+    rpm_controller: bool,
+    metrics: Option<MetricsRegistry>,
+    progress: Arc<WorkerProgress>,
 
     inv_log: Option<InvocationLoggerRef>,
-    quit_rx: broadcast::Receiver<()>,
+    cancel: CancellationToken,
 }
 
 impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
@@ -76,50 +230,113 @@ impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
         seed: u64,
         iat_gen: G,
         sync: Arc<WorkerSync>,
+        start: Arc<Barrier>,
         minute_range: MinuteRange,
         backend: B,
+        retry_policy: RetryPolicy,
         minio_address: &str,
         bucket_name: &str,
+        pacing_gain: Option<f64>,
+        tranquilizer: Option<Tranquilizer>,
+        rpm_controller: bool,
+        metrics: Option<MetricsRegistry>,
         inv_log: Option<InvocationLoggerRef>,
-        quit_rx: broadcast::Receiver<()>,
+        cancel: CancellationToken,
     ) -> Result<Self, Error> {
-        let mut wreq =
-            ::serde_json::from_str::<WorkloadRequest>(&row.mapped_wreq).map_err(|err| {
-                Error::Deserialization {
-                    msg: format!("mapped WorkloadRequest: {:?}", row.mapped_wreq).into_boxed_str(),
-                    source: err,
-                }
-            })?;
-        crate::fixer::fix_fbpml_payload(&mut wreq, minio_address, bucket_name).map_err(|err| {
-            Error::FbpmlPayloadFix {
-                wreq: Box::new(wreq.clone()),
-                source: err,
-            }
-        })?;
+        let wreq = parse_wreq(&row.mapped_wreq, minio_address, bucket_name)?;
+        let progress = Arc::new(WorkerProgress::new(wreq.bench.clone()));
 
         Ok(Self {
             sync,
+            start,
             minute_range,
 
             _pavg: row.pavg,
             rpm: row.rpm,
             wreq,
             backend,
+            retry_policy,
 
             iat_gen,
             rng: FastRng::seed_from_u64(seed),
+            pacing_gain,
+            tranquilizer,
+            rpm_controller,
+            metrics,
+            progress,
 
             inv_log,
-            quit_rx,
+            cancel,
         })
     }
 
+    /// A handle to this worker's live progress, to be kept by the caller (before spawning the
+    /// worker's `run` future) so a status can still be reported if the worker is later aborted.
+    pub(crate) fn progress(&self) -> Arc<WorkerProgress> {
+        Arc::clone(&self.progress)
+    }
+
+    /// Issues one invocation against `self.backend`, retrying per `self.retry_policy` with a
+    /// hard per-attempt [`tokio::time::timeout`] and full-jitter exponential backoff drawn from
+    /// `self.rng`. The whole retry budget — every attempt plus every backoff sleep — is clamped
+    /// to `minute_deadline`, so a flaky backend can never push invocations past the minute
+    /// boundary `self.run`'s own `minute_end` timer enforces. `self.cancel` is only checked
+    /// before starting a new attempt (and during the backoff sleep between attempts): per
+    /// chunk2-2's cancellation contract, an in-flight `issue` is always awaited to completion
+    /// rather than abandoned mid-flight.
+    async fn issue_with_retry(
+        &mut self,
+        invocation_id: InvocationId,
+        minute: u16,
+        minute_deadline: Instant,
+    ) -> Result<(), RetryError<B::Error>> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            if self.cancel.is_cancelled() {
+                return Err(RetryError::Cancelled);
+            }
+            let remaining = minute_deadline.duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(RetryError::Exhausted);
+            }
+            let attempt_timeout = remaining.min(self.retry_policy.attempt_timeout);
+
+            let outcome = timeout(
+                attempt_timeout,
+                self.backend.issue(invocation_id.clone(), &self.wreq, minute, attempt_timeout),
+            )
+            .await;
+            let err = match outcome {
+                Ok(Ok(())) => return Ok(()),
+                Ok(Err(err)) => Some(err),
+                Err(_elapsed) => None,
+            };
+
+            if attempt >= self.retry_policy.max_attempts {
+                return Err(err.map_or(RetryError::Exhausted, RetryError::Backend));
+            }
+
+            let backoff = self.retry_policy.backoff(attempt, &mut self.rng);
+            let remaining = minute_deadline.duration_since(Instant::now());
+            if backoff >= remaining {
+                return Err(RetryError::Exhausted);
+            }
+            debug!(attempt, ?backoff, %invocation_id, "Retrying failed invocation");
+            ::tokio::select! {
+                biased;
+                () = self.cancel.cancelled() => return Err(RetryError::Cancelled),
+                () = sleep(backoff) => {}
+            }
+        }
+    }
+
     #[instrument(level = Level::INFO, skip(self), fields(function_id = %self.wreq.bench, self._pavg))] // FIXME?
-    pub async fn run(mut self) -> Result<u64, Error> {
+    pub async fn run(mut self) -> Result<WorkerStatus, Error> {
         const ONE_MINUTE: Duration = Duration::from_secs(60);
 
-        // Workers won't start until kicked
-        self.quit_rx.recv().await.expect("TODO"); // FIXME: error handling
+        // Workers won't start until SourceClient::run releases the start gate
+        self.start.wait().await;
         let mut num_requests = 0;
         let t_start = Instant::now();
 
@@ -146,12 +363,49 @@ impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
 
             self.sync.barrier.wait().await;
             info!(minute, rpm, "alive.for" = %::humantime::format_duration(t_start.elapsed()));
+            self.progress.last_minute.store(minute as u32, Ordering::Relaxed);
+
+            let minute_start = Instant::now();
+            minute_end.as_mut().reset(minute_start + ONE_MINUTE);
+            let mut pacing = self
+                .pacing_gain
+                .map(|gain| PacingController::new(gain, minute_start));
+            let rpm_ctrl = self.rpm_controller.then(|| RpmController::new(*rpm));
+            let mut issued_this_minute: u64 = 0;
+            let mut intended_elapsed = Duration::ZERO;
+            if let Some(ref metrics) = self.metrics {
+                metrics.start_minute(&self.wreq.bench, *rpm);
+            }
 
-            minute_end.as_mut().reset(Instant::now() + ONE_MINUTE);
             loop {
                 // NOTE: Keep the loop like this (rather than, e.g., `while let`) to make sure we
-                // always await on `quit_rx` too.
-                let iat = iats.next().map(Duration::from_micros).unwrap_or(ONE_MINUTE);
+                // always await on `self.cancel.cancelled()` too.
+                // `intended_elapsed` accumulates the raw, uncorrected schedule (mirroring
+                // `PacingController::correct`'s own pre-increment `intended_before`), so
+                // `record_drift` below reports actual drift against the generated schedule
+                // rather than one that converges toward zero by construction whenever
+                // pacing/the tranquilizer are active.
+                let (iat, raw_iat) = if let Some(ref rpm_ctrl) = rpm_ctrl {
+                    // Deadline-driven: bypass the IAT stream entirely and fire on whatever
+                    // schedule converges on `rpm`.
+                    let iat = rpm_ctrl.next_sleep(issued_this_minute, minute_start);
+                    (iat, iat)
+                } else {
+                    let raw_iat = iats.next().map(Duration::from_micros).unwrap_or(ONE_MINUTE);
+                    let mut iat = raw_iat;
+                    if let Some(ref mut pacing) = pacing {
+                        iat = pacing.correct(iat);
+                    }
+                    if let Some(ref tranquilizer) = self.tranquilizer {
+                        iat += tranquilizer.extra_delay(iat);
+                    }
+                    (iat, raw_iat)
+                };
+                if let Some(ref metrics) = self.metrics {
+                    let drift = minute_start.elapsed().as_secs_f64() - intended_elapsed.as_secs_f64();
+                    metrics.record_drift(&self.wreq.bench, drift);
+                }
+                intended_elapsed += raw_iat;
 
                 ::tokio::select! {
                     biased;
@@ -168,16 +422,8 @@ impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
                     // Workers do their best to honor the minute limit, possibly at the cost of
                     // producing fewer requests than expected. Let's just do this for now.
 
-                    quit_res = self.quit_rx.recv() => {
-                        match quit_res {
-                            Ok(()) => {
-                                // TODO?
-                                warn!("Received quit notification!");
-                                //self.client_handle.abort();
-                            }
-                            Err(err) => error!("Quit channel unexpectedly emitted: {err:#}"),
-                        }
-                        // We should probably break out of the (outer) loop in any case
+                    () = self.cancel.cancelled() => {
+                        warn!("Received cancellation notification!");
                         break 'minutes;
                     }
 
@@ -192,19 +438,45 @@ impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
                             self.sync.invoc_id.fetch_add(1, Ordering::AcqRel)
                         );
                         debug_assert!(!invocation_id.is_heap_allocated());
-                        if let Err(err) = self
-                            .backend
-                            .issue(
-                                invocation_id.clone(),
-                                &self.wreq,
-                                minute,
-                                minute_end.deadline().duration_since(Instant::now()),
-                            )
-                            .await
-                        {
-                            error!(error = ?err, %invocation_id, "Failed to issue request");
+                        if let Some(ref metrics) = self.metrics {
+                            metrics.adjust_in_flight(&self.wreq.bench, 1);
+                        }
+                        let issue_result = self
+                            .issue_with_retry(invocation_id.clone(), minute, minute_end.deadline())
+                            .await;
+                        if let Some(ref metrics) = self.metrics {
+                            metrics.adjust_in_flight(&self.wreq.bench, -1);
+                        }
+                        if let Some(ref tranquilizer) = self.tranquilizer {
+                            tranquilizer.record_completion();
+                        }
+                        if let Err(err) = issue_result {
+                            match err {
+                                RetryError::Backend(err) => {
+                                    error!(error = ?err, %invocation_id, "Failed to issue request");
+                                    self.progress.retries_exhausted.fetch_add(1, Ordering::Relaxed);
+                                    if let Some(ref metrics) = self.metrics {
+                                        metrics.record_failure(&self.wreq.bench);
+                                    }
+                                }
+                                RetryError::Exhausted => {
+                                    warn!(%invocation_id, "Exhausted retry budget for invocation");
+                                    self.progress.retries_exhausted.fetch_add(1, Ordering::Relaxed);
+                                    if let Some(ref metrics) = self.metrics {
+                                        metrics.record_failure(&self.wreq.bench);
+                                    }
+                                }
+                                RetryError::Cancelled => {
+                                    warn!(%invocation_id, "Cancelled while retrying invocation");
+                                }
+                            }
                         } else {
                             num_requests += 1;
+                            issued_this_minute += 1;
+                            self.progress.invocations_issued.store(num_requests, Ordering::Relaxed);
+                            if let Some(ref metrics) = self.metrics {
+                                metrics.record_issued(&self.wreq.bench);
+                            }
                             trace!(%invocation_id, ?num_requests, "Request issued successfully");
                             if let Some(ref inv_log) = self.inv_log {
                                 inv_log.log(self.wreq.bench.clone(), invocation_id).await;
@@ -215,6 +487,229 @@ impl<G: IatGenerator, B: Backend> FunctionWorker<G, B> {
             }
         }
 
-        Ok(num_requests)
+        Ok(self.progress.snapshot(WorkerOutcome::Completed))
+    }
+}
+
+/// Placeholder `bench` reported in the [`WorkerStatus`] of a [`ReplayWorker`], which drives
+/// invocations for every `bench` named in the replayed log rather than a single one.
+const REPLAY_BENCH: &str = "<replay>";
+
+/// Re-issues a previously recorded `(InvocationId, bench)` sequence against a [`Backend`], in
+/// the exact order it was logged, bypassing [`IatGenerator`](super::iat::IatGenerator) sampling
+/// entirely so the same schedule can be driven against a different `Backend` for a
+/// request-for-request A/B comparison.
+#[derive(Debug)]
+pub(crate) struct ReplayWorker<B: Backend> {
+    entries: ::std::vec::IntoIter<(CompactString, WorkloadRequest)>,
+    backend: B,
+    /// Passed through to each [`Backend::issue`] call, in lieu of a per-minute deadline (there
+    /// is no "minute" during a replay).
+    issue_timeout: Duration,
+    metrics: Option<MetricsRegistry>,
+    progress: Arc<WorkerProgress>,
+    cancel: CancellationToken,
+}
+
+impl<B: Backend> ReplayWorker<B> {
+    pub(crate) fn new(
+        entries: Vec<(CompactString, WorkloadRequest)>,
+        backend: B,
+        issue_timeout: Duration,
+        metrics: Option<MetricsRegistry>,
+        cancel: CancellationToken,
+    ) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            backend,
+            issue_timeout,
+            metrics,
+            progress: Arc::new(WorkerProgress::new(CompactString::from(REPLAY_BENCH))),
+            cancel,
+        }
+    }
+
+    /// A handle to this worker's live progress, to be kept by the caller (before spawning the
+    /// worker's `run` future) so a status can still be reported if the worker is later aborted.
+    pub(crate) fn progress(&self) -> Arc<WorkerProgress> {
+        Arc::clone(&self.progress)
+    }
+
+    #[instrument(level = Level::INFO, skip(self), fields(num_entries = self.entries.len()))]
+    pub(crate) async fn run(self) -> Result<WorkerStatus, Error> {
+        let Self {
+            entries,
+            mut backend,
+            issue_timeout,
+            metrics,
+            progress,
+            cancel,
+        } = self;
+        let mut num_requests = 0;
+
+        for (invocation_id, wreq) in entries {
+            // NOTE: Like `FunctionWorker`, we only check for a cancellation *between*
+            // invocations, never racing it against an in-flight `Backend::issue` call, so a
+            // cancel never cuts off a request that's already underway.
+            if cancel.is_cancelled() {
+                warn!("Received cancellation notification!");
+                break;
+            }
+
+            if let Some(ref metrics) = metrics {
+                metrics.adjust_in_flight(&wreq.bench, 1);
+            }
+            let issue_result = backend
+                .issue(invocation_id.clone(), &wreq, 0, issue_timeout)
+                .await;
+            if let Some(ref metrics) = metrics {
+                metrics.adjust_in_flight(&wreq.bench, -1);
+            }
+            if let Err(err) = issue_result {
+                error!(error = ?err, %invocation_id, bench = %wreq.bench, "Failed to re-issue request");
+                if let Some(ref metrics) = metrics {
+                    metrics.record_failure(&wreq.bench);
+                }
+            } else {
+                num_requests += 1;
+                progress.invocations_issued.store(num_requests, Ordering::Relaxed);
+                if let Some(ref metrics) = metrics {
+                    metrics.record_issued(&wreq.bench);
+                }
+                trace!(%invocation_id, bench = %wreq.bench, ?num_requests, "Request re-issued successfully");
+            }
+        }
+
+        Ok(progress.snapshot(WorkerOutcome::Completed))
+    }
+}
+
+/// Placeholder `bench` reported in the [`WorkerStatus`] of a [`TraceReplayWorker`], which drives
+/// invocations for every `bench` named in the replayed trace rather than a single one.
+const TRACE_REPLAY_BENCH: &str = "<trace-replay>";
+
+/// How [`TraceReplayWorker::run`] paces re-issued requests.
+#[derive(Debug, Clone, Copy)]
+pub enum ReplayPacing {
+    /// Sleep each entry's recorded inter-arrival gap (scaled by `1 / speedup`) before issuing it.
+    Timed { speedup: f64 },
+    /// Ignore recorded timestamps; issue every request back-to-back.
+    AsFastAsPossible,
+}
+
+/// Re-issues a previously captured trace log (one [`TraceEntry`] per record, in whatever format
+/// it was written with — see [`Codec`](crate::codec::Codec)) against a [`Backend`], honoring (or,
+/// in [`ReplayPacing::AsFastAsPossible`] mode, ignoring) the original inter-arrival gaps.
+/// Complements [`ReplayWorker`], which re-issues a bare `(InvocationId, bench)` schedule against
+/// the original CSV's `WorkloadRequest`s with no timing at all: `TraceReplayWorker` instead
+/// replays a full captured production trace, timing included, against a different `Backend`,
+/// closing the record/replay loop for deterministic production-trace reruns.
+#[derive(Debug)]
+pub(crate) struct TraceReplayWorker<B: Backend> {
+    entries: ::std::vec::IntoIter<TraceEntry>,
+    backend: B,
+    pacing: ReplayPacing,
+    /// Passed through to each [`Backend::issue`] call, in lieu of a per-minute deadline (there
+    /// is no "minute" during a replay).
+    issue_timeout: Duration,
+    metrics: Option<MetricsRegistry>,
+    progress: Arc<WorkerProgress>,
+    cancel: CancellationToken,
+}
+
+impl<B: Backend> TraceReplayWorker<B> {
+    /// `entries` need not be pre-sorted or pre-filtered: they're sorted by `epoch_us` and
+    /// restricted to `window` here.
+    pub(crate) fn new(
+        mut entries: Vec<TraceEntry>,
+        window: TraceWindow,
+        backend: B,
+        pacing: ReplayPacing,
+        issue_timeout: Duration,
+        metrics: Option<MetricsRegistry>,
+        cancel: CancellationToken,
+    ) -> Self {
+        entries.retain(|entry| window.contains(entry.epoch_us));
+        entries.sort_by_key(|entry| entry.epoch_us);
+        Self {
+            entries: entries.into_iter(),
+            backend,
+            pacing,
+            issue_timeout,
+            metrics,
+            progress: Arc::new(WorkerProgress::new(CompactString::from(TRACE_REPLAY_BENCH))),
+            cancel,
+        }
+    }
+
+    /// A handle to this worker's live progress, to be kept by the caller (before spawning the
+    /// worker's `run` future) so a status can still be reported if the worker is later aborted.
+    pub(crate) fn progress(&self) -> Arc<WorkerProgress> {
+        Arc::clone(&self.progress)
+    }
+
+    #[instrument(level = Level::INFO, skip(self), fields(num_entries = self.entries.len()))]
+    pub(crate) async fn run(self) -> Result<WorkerStatus, Error> {
+        let Self {
+            entries,
+            mut backend,
+            pacing,
+            issue_timeout,
+            metrics,
+            progress,
+            cancel,
+        } = self;
+        let mut num_requests = 0;
+        let mut prev_epoch_us = None;
+
+        'entries: for entry in entries {
+            // NOTE: Like `ReplayWorker`, we only check for a cancellation *between* invocations,
+            // never racing it against an in-flight `Backend::issue` call, so a cancel never cuts
+            // off a request that's already underway.
+            if cancel.is_cancelled() {
+                warn!("Received cancellation notification!");
+                break;
+            }
+
+            if let ReplayPacing::Timed { speedup } = pacing {
+                let delta_us = prev_epoch_us.map_or(0, |prev| entry.epoch_us.saturating_sub(prev));
+                let sleep_us = (delta_us as f64 / speedup).max(0.0) as u64;
+                ::tokio::select! {
+                    biased;
+                    () = cancel.cancelled() => {
+                        warn!("Received cancellation notification!");
+                        break 'entries;
+                    }
+                    () = sleep(Duration::from_micros(sleep_us)) => {}
+                }
+            }
+            prev_epoch_us = Some(entry.epoch_us);
+
+            let TraceEntry { invocation_id, wreq, .. } = entry;
+            if let Some(ref metrics) = metrics {
+                metrics.adjust_in_flight(&wreq.bench, 1);
+            }
+            let issue_result = backend
+                .issue(invocation_id.clone(), &wreq, 0, issue_timeout)
+                .await;
+            if let Some(ref metrics) = metrics {
+                metrics.adjust_in_flight(&wreq.bench, -1);
+            }
+            if let Err(err) = issue_result {
+                error!(error = ?err, %invocation_id, bench = %wreq.bench, "Failed to re-issue request");
+                if let Some(ref metrics) = metrics {
+                    metrics.record_failure(&wreq.bench);
+                }
+            } else {
+                num_requests += 1;
+                progress.invocations_issued.store(num_requests, Ordering::Relaxed);
+                if let Some(ref metrics) = metrics {
+                    metrics.record_issued(&wreq.bench);
+                }
+                trace!(%invocation_id, bench = %wreq.bench, ?num_requests, "Request re-issued successfully");
+            }
+        }
+
+        Ok(progress.snapshot(WorkerOutcome::Completed))
     }
 }